@@ -0,0 +1,118 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{AocError, Solution};
+
+/// Min, median and max duration of repeated runs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let min = samples.first().copied().unwrap_or_default();
+        let max = samples.last().copied().unwrap_or_default();
+        let median = match samples.len() {
+            0 => Duration::default(),
+            len if len % 2 == 0 => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+            len => samples[len / 2],
+        };
+        Stats { min, median, max }
+    }
+}
+
+/// Timings of a single day, with each step measured separately.
+#[derive(Debug)]
+pub struct Timings {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timings {
+    fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [("parse", &self.parse), ("part1", &self.part1), ("part2", &self.part2)]
+    }
+}
+
+pub(crate) fn bench<S: Solution>(input: &str, runs: usize) -> Result<Timings, AocError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(black_box(&parsed))?);
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(black_box(&parsed))?);
+        part2.push(start.elapsed());
+    }
+
+    Ok(Timings {
+        day: S::DAY,
+        runs,
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_samples(part1),
+        part2: Stats::from_samples(part2),
+    })
+}
+
+pub fn table(timings: &[Timings]) -> String {
+    let mut out = format!("{:<5}{:<8}{:>12}{:>12}{:>12}\n", "Day", "Step", "Min", "Median", "Max");
+    for t in timings {
+        for (step, stats) in t.steps() {
+            out += &format!("{:<5}{:<8}{:>12}{:>12}{:>12}\n",
+                            format!("{:02}", t.day),
+                            step,
+                            format!("{:.2?}", stats.min),
+                            format!("{:.2?}", stats.median),
+                            format!("{:.2?}", stats.max));
+        }
+    }
+    out
+}
+
+pub fn json(timings: &[Timings]) -> String {
+    let records: Vec<String> = timings.iter()
+        .flat_map(|t| t.steps().into_iter().map(move |(step, stats)| (t, step, stats)))
+        .map(|(t, step, stats)| format!(
+            r#"  {{"day": {}, "step": "{}", "runs": {}, "min_ns": {}, "median_ns": {}, "max_ns": {}}}"#,
+            t.day, step, t.runs, stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos()))
+        .collect();
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::Stats;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn stats_odd_samples() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(stats, Stats { min: Duration::from_millis(1), median: Duration::from_millis(3), max: Duration::from_millis(5) });
+    }
+
+    #[test]
+    fn stats_even_samples() {
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 8]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+}
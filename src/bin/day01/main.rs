@@ -0,0 +1,144 @@
+use std::env;
+use std::process::ExitCode;
+
+use aoc2021::{AocError, day01, exit_code};
+use aoc2021::day01::anomaly::AnomalyDetector;
+use aoc2021::day01::report::DepthReport;
+use aoc2021::day01::SonarSweep;
+use aoc2021::input::{day_input, day_input_path, open_input};
+
+/// Moving average window of anomaly detection, unless set with `--window`.
+const DEFAULT_ANOMALY_WINDOW: usize = 10;
+
+enum ReportFormat {
+    Table,
+    Json,
+}
+
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+/// Usage: day01 [input] [--window N] [--stream] [--progress N] [--report table|json] [--anomalies SIGMA]
+///
+/// With `--stream` the input is processed line by line in constant memory. `--progress N` implies it
+/// and prints the running counts to stderr after every N readings. `--report` prints statistics
+/// of the depth profile instead of the answers, with the trend over the window (or both parts' windows).
+/// `--anomalies` lists readings more than SIGMA standard deviations away from the moving average
+/// of the previous readings over the window.
+fn run() -> Result<(), AocError> {
+    let mut path = None;
+    let mut window = None;
+    let mut stream = false;
+    let mut progress = None;
+    let mut report = None;
+    let mut sigma = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--window" => window = Some(positive(&arg, args.next())?),
+            "--stream" => stream = true,
+            "--progress" => {
+                progress = Some(positive(&arg, args.next())? as u64);
+                stream = true;
+            }
+            "--report" => report = Some(report_format(args.next())?),
+            "--anomalies" => sigma = Some(parse_sigma(args.next())?),
+            _ if path.is_none() => path = Some(arg),
+            other => return Err(AocError::Invalid(format!("Unexpected argument: {}", other))),
+        }
+    }
+
+    if let Some(sigma) = sigma {
+        return find_anomalies(path.as_deref(), window.unwrap_or(DEFAULT_ANOMALY_WINDOW), sigma);
+    }
+
+    let windows = match window {
+        Some(window) => vec![window],
+        None => vec![1, 3],
+    };
+    if let Some(format) = report {
+        if stream {
+            return Err(AocError::Invalid("Report can't be combined with streaming".to_owned()));
+        }
+        let numbers = day01::parse(&day_input(1, path.as_deref())?)?;
+        let report = DepthReport::new(&numbers, &windows);
+        match format {
+            ReportFormat::Table => print!("{}", report.table()),
+            ReportFormat::Json => print!("{}", report.json()),
+        }
+        return Ok(());
+    }
+
+    let increases = if stream {
+        sweep(path.as_deref(), &windows, progress)?
+    } else {
+        let numbers = day01::parse(&day_input(1, path.as_deref())?)?;
+        windows.iter().map(|window| day01::window_increases(&numbers, *window) as u64).collect()
+    };
+
+    match window {
+        Some(window) => println!("Window {}: {}", window, increases[0]),
+        None => {
+            println!("Part 1: {}", increases[0]);
+            println!("Part 2: {}", increases[1]);
+        }
+    }
+    Ok(())
+}
+
+fn sweep(path: Option<&str>, windows: &[usize], progress: Option<u64>) -> Result<Vec<u64>, AocError> {
+    let reader = open_input(&day_input_path(1, path))?;
+    let mut sweeps: Vec<SonarSweep> = windows.iter().map(|window| SonarSweep::new(*window)).collect();
+    day01::for_each_depth(reader, |depth| {
+        for sweep in sweeps.iter_mut() {
+            sweep.push(depth);
+        }
+        let readings = sweeps[0].readings();
+        if progress.is_some_and(|every| readings.is_multiple_of(every)) {
+            let counts: Vec<String> = sweeps.iter()
+                .map(|sweep| format!("window {}: {}", sweep.window(), sweep.increases()))
+                .collect();
+            eprintln!("{} readings, {}", readings, counts.join(", "));
+        }
+    })?;
+    Ok(sweeps.iter().map(|sweep| sweep.increases()).collect())
+}
+
+fn find_anomalies(path: Option<&str>, window: usize, sigma: f64) -> Result<(), AocError> {
+    let reader = open_input(&day_input_path(1, path))?;
+    let mut detector = AnomalyDetector::new(window, sigma);
+    let mut found = 0;
+    let readings = day01::for_each_depth(reader, |depth| {
+        if let Some(anomaly) = detector.push(depth) {
+            found += 1;
+            println!("Reading {}: {}, expected {:.1} to {:.1} (mean {:.1}, {:.1} sigma)",
+                     anomaly.index, anomaly.depth, anomaly.expected.0, anomaly.expected.1,
+                     anomaly.mean, anomaly.deviations);
+        }
+    })?;
+    println!("{} anomalies in {} readings", found, readings);
+    Ok(())
+}
+
+fn parse_sigma(value: Option<String>) -> Result<f64, AocError> {
+    let value = value.unwrap_or_default();
+    value.parse().ok()
+        .filter(|sigma: &f64| sigma.is_finite() && *sigma >= 0.0)
+        .ok_or_else(|| AocError::Invalid(format!("--anomalies must be a non-negative number, got {:?}", value)))
+}
+
+fn positive(flag: &str, value: Option<String>) -> Result<usize, AocError> {
+    let value = value.unwrap_or_default();
+    value.parse().ok()
+        .filter(|n| *n > 0)
+        .ok_or_else(|| AocError::Invalid(format!("{} must be a positive number, got {:?}", flag, value)))
+}
+
+fn report_format(value: Option<String>) -> Result<ReportFormat, AocError> {
+    match value.as_deref() {
+        Some("table") => Ok(ReportFormat::Table),
+        Some("json") => Ok(ReportFormat::Json),
+        other => Err(AocError::Invalid(format!("Report format must be table or json, got {:?}", other.unwrap_or("")))),
+    }
+}
@@ -0,0 +1,101 @@
+use std::env;
+use std::io;
+use std::process::ExitCode;
+
+use aoc2021::{AocError, day02, exit_code, Part};
+use aoc2021::day02::{Model, Policy};
+use aoc2021::day02::repl::{repl, Session};
+use aoc2021::day02::script;
+use aoc2021::day02::trajectory::{Export, record};
+use aoc2021::input::day_input;
+
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+/// Usage: day02 [input] [--model position|aim] [--policy error|clamp|allow] [--validate]
+///               [--trajectory csv|json|ascii|svg]
+///        day02 --repl [script]
+///
+/// Without a model prints answers of both parts, part 1 uses the position model and part 2 the aim model.
+/// The policy decides what happens when a command takes depth above the surface or aim below zero, by default it's an error.
+/// With clamp or allow the offending lines are printed to stderr. `--validate` only checks the script,
+/// printing every such line for each model, and fails if there was any.
+/// `--trajectory` prints the state after every command instead of the answer, with the aim model unless one is given.
+/// `--repl` starts an interactive session showing both models, after executing the script if one is given.
+fn run() -> Result<(), AocError> {
+    let mut path = None;
+    let mut model = None;
+    let mut policy = Policy::Error;
+    let mut validate = false;
+    let mut trajectory = None;
+    let mut interactive = false;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--model" => {
+                let name = args.next().unwrap_or_default();
+                model = Some(name.parse::<Model>().map_err(AocError::Invalid)?);
+            }
+            "--policy" => policy = args.next().unwrap_or_default().parse().map_err(AocError::Invalid)?,
+            "--validate" => validate = true,
+            "--repl" => interactive = true,
+            "--trajectory" => trajectory = Some(args.next().unwrap_or_default().parse::<Export>().map_err(AocError::Invalid)?),
+            _ if path.is_none() => path = Some(arg),
+            other => return Err(AocError::Invalid(format!("Unexpected argument: {}", other))),
+        }
+    }
+
+    if interactive {
+        let session = match path {
+            Some(path) => Session::from_steps(&day02::parse(&day_input(2, Some(&path))?)?)?,
+            None => Session::default(),
+        };
+        repl(session, io::stdin().lock(), io::stdout())?;
+        return Ok(());
+    }
+
+    // Scripts are run without expanding repeats, only the trajectory needs every step.
+    let statements = script::parse_script(&day_input(2, path.as_deref())?)?;
+    if let Some(format) = trajectory {
+        let steps = script::expand(&statements, script::MAX_COMMANDS)?;
+        let model = model.unwrap_or(Model::Aim);
+        let (trajectory, diagnostics) = record(model.start().as_mut(), &steps, policy)?;
+        diagnostics.iter().for_each(|diagnostic| eprintln!("{}: {}", model, diagnostic));
+        print!("{}", trajectory.render(format)?);
+        return Ok(());
+    }
+    let models = model.map_or(Model::ALL.to_vec(), |model| vec![model]);
+    if validate {
+        let mut problems = 0;
+        for model in models {
+            for diagnostic in script::run(model.start().as_mut(), &statements, Policy::Allow, script::MAX_COMMANDS)? {
+                println!("{}: {}", model, diagnostic);
+                problems += 1;
+            }
+        }
+        return match problems {
+            0 => {
+                println!("No command takes depth or aim below zero");
+                Ok(())
+            }
+            n => Err(AocError::Invalid(format!("{} commands take depth or aim below zero", n))),
+        };
+    }
+
+    let answer = |model: Model| {
+        let mut navigation = model.start();
+        for diagnostic in script::run(navigation.as_mut(), &statements, policy, script::MAX_COMMANDS)? {
+            eprintln!("{}: {}", model, diagnostic);
+        }
+        navigation.answer()
+    };
+    match model {
+        Some(model) => println!("{}: {}", model, answer(model)?),
+        None => {
+            println!("Part 1: {}", answer(Model::for_part(Part::One))?);
+            println!("Part 2: {}", answer(Model::for_part(Part::Two))?);
+        }
+    }
+    Ok(())
+}
@@ -1,14 +1,15 @@
-mod board;
-mod parser;
-mod part1;
-mod part2;
+use std::process::ExitCode;
 
-fn main() -> Result<(), String> {
-    let input = aoc2021::get_input("inputs/04.txt");
-    let (numbers, boards) = parser::parse(&input)?;
-    let solution1 = part1::solve(&numbers, boards.clone());
-    println!("Part 1: {}", solution1);
-    let solution2 = part2::solve(numbers, boards.clone());
-    println!("Part 2: {}", solution2);
+use aoc2021::{AocError, Day04, exit_code, get_input, Solution};
+
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+fn run() -> Result<(), AocError> {
+    let input = get_input(4)?;
+    let parsed = Day04::parse(&input)?;
+    println!("Part 1: {}", Day04::part1(&parsed)?);
+    println!("Part 2: {}", Day04::part2(&parsed)?);
     Ok(())
-}
\ No newline at end of file
+}
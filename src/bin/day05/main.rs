@@ -1,19 +1,15 @@
-use aoc2021::{BoxError, get_input};
-use crate::parser::parse;
+use std::process::ExitCode;
 
-mod model;
-mod parser;
-mod part1;
-mod part2;
+use aoc2021::{AocError, Day05, exit_code, get_input, Solution};
 
-fn main() -> Result<(), BoxError> {
-    let input = get_input("inputs/05.txt");
-    let lines = parse(&input)?;
-
-    let solution1 = part1::solve(&lines, part1::line_filter);
-    println!("Part 1: {}", solution1);
-    let solution2 = part1::solve(&lines, part2::line_filter);
-    println!("Part 2: {}", solution2);
+fn main() -> ExitCode {
+    exit_code(run())
+}
 
+fn run() -> Result<(), AocError> {
+    let input = get_input(5)?;
+    let parsed = Day05::parse(&input)?;
+    println!("Part 1: {}", Day05::part1(&parsed)?);
+    println!("Part 2: {}", Day05::part2(&parsed)?);
     Ok(())
-}
\ No newline at end of file
+}
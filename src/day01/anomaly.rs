@@ -0,0 +1,85 @@
+use crate::day01::Window;
+
+/// Reading that is further from the moving average of the previous readings than allowed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Anomaly {
+    pub index: u64,
+    pub depth: u32,
+    /// Moving average of the previous readings.
+    pub mean: f64,
+    /// Lowest and highest depth that wouldn't have been flagged.
+    pub expected: (f64, f64),
+    /// Distance from the mean in standard deviations, infinite when the previous readings were all the same.
+    pub deviations: f64,
+}
+
+/// Flags readings deviating from the average of the previous `window` readings by more than `sigma`
+/// standard deviations of them. Readings are checked only once there are `window` previous ones.
+/// Flagged readings stay in the window, so a lasting change of level is reported only until the
+/// window catches up with it.
+#[derive(Debug, Clone)]
+pub struct AnomalyDetector {
+    previous: Window,
+    sigma: f64,
+    index: u64,
+}
+
+impl AnomalyDetector {
+    pub fn new(window: usize, sigma: f64) -> Self {
+        AnomalyDetector { previous: Window::new(window), sigma, index: 0 }
+    }
+
+    pub fn push(&mut self, depth: u32) -> Option<Anomaly> {
+        let anomaly = if self.previous.is_full() && self.previous.size() > 0 {
+            let mean = self.previous.mean();
+            let tolerance = self.sigma * self.previous.std_dev();
+            let distance = (depth as f64 - mean).abs();
+            (distance > tolerance).then(|| Anomaly {
+                index: self.index,
+                depth,
+                mean,
+                expected: (mean - tolerance, mean + tolerance),
+                deviations: distance / self.previous.std_dev(),
+            })
+        } else {
+            None
+        };
+        self.previous.push(depth);
+        self.index += 1;
+        anomaly
+    }
+}
+
+/// All anomalies of the readings, see [AnomalyDetector].
+pub fn anomalies(depths: &[u32], window: usize, sigma: f64) -> Vec<Anomaly> {
+    let mut detector = AnomalyDetector::new(window, sigma);
+    depths.iter().filter_map(|depth| detector.push(*depth)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day01::anomaly::anomalies;
+
+    #[test]
+    fn spike() {
+        let depths = [100, 102, 101, 103, 102, 950, 104, 103, 105];
+        let found = anomalies(&depths, 4, 3.0);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].index, found[0].depth), (5, 950));
+        assert!((found[0].mean - 102.0).abs() < 1e-9);
+        assert!(found[0].expected.0 < 102.0 && found[0].expected.1 < 110.0);
+    }
+
+    #[test]
+    fn spike_in_window_hides_neighbours() {
+        let depths = [100, 100, 100, 100, 500, 130, 100];
+        let found: Vec<u64> = anomalies(&depths, 4, 2.0).iter().map(|a| a.index).collect();
+        assert_eq!(found, vec![4]);
+    }
+
+    #[test]
+    fn gradual_descent_is_normal() {
+        let depths: Vec<u32> = (0..100).map(|d| 200 + d * 3 + d % 2).collect();
+        assert!(anomalies(&depths, 5, 3.0).is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use crate::{as_vec, AocError, Solution};
+
+pub mod anomaly;
+pub mod report;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Self::Answer1, AocError> {
+        Ok(part1(numbers))
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Self::Answer2, AocError> {
+        Ok(part2(numbers))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<u32>, AocError> {
+    as_vec(input)
+}
+
+pub fn part1(numbers: &[u32]) -> u32 {
+    window_increases(numbers, 1)
+}
+
+pub fn part2(numbers: &[u32]) -> u32 {
+    window_increases(numbers, 3)
+}
+
+/// Counts how many times the sum of `window` consecutive numbers is larger than the previous sum.
+/// Neighbouring windows share all numbers but `a[i]` and `a[i + window]`, so only those are compared.
+pub fn window_increases(numbers: &[u32], window: usize) -> u32 {
+    numbers.iter()
+        .zip(numbers.iter().skip(window))
+        .filter(|(first, last)| last > first)
+        .count() as u32
+}
+
+/// The last `size` readings with their running sum and sum of squares, updated in constant time.
+#[derive(Debug, Clone)]
+pub struct Window {
+    size: usize,
+    readings: VecDeque<u32>,
+    sum: u64,
+    sum_of_squares: u128,
+}
+
+impl Window {
+    pub fn new(size: usize) -> Self {
+        Window { size, readings: VecDeque::with_capacity(size), sum: 0, sum_of_squares: 0 }
+    }
+
+    /// Adds the reading and returns the one that dropped out of a full window,
+    /// i.e. the reading `size` positions before this one.
+    pub fn push(&mut self, depth: u32) -> Option<u32> {
+        if self.size == 0 {
+            return Some(depth);
+        }
+        let dropped = if self.is_full() { self.readings.pop_front() } else { None };
+        if let Some(dropped) = dropped {
+            self.sum -= dropped as u64;
+            self.sum_of_squares -= (dropped as u128).pow(2);
+        }
+        self.readings.push_back(depth);
+        self.sum += depth as u64;
+        self.sum_of_squares += (depth as u128).pow(2);
+        dropped
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.readings.len() == self.size
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.sum
+    }
+
+    /// Mean of the readings in the window, zero if it's empty.
+    pub fn mean(&self) -> f64 {
+        match self.readings.len() {
+            0 => 0.0,
+            len => self.sum as f64 / len as f64,
+        }
+    }
+
+    /// Population standard deviation of the readings in the window, zero if it's empty.
+    pub fn std_dev(&self) -> f64 {
+        let len = self.readings.len() as u128;
+        if len == 0 {
+            return 0.0;
+        }
+        // n * sum(x^2) - sum(x)^2 is exact in integers and never negative.
+        let spread = len * self.sum_of_squares - (self.sum as u128).pow(2);
+        (spread as f64).sqrt() / len as f64
+    }
+}
+
+/// Running count of window increases over readings that arrive one at a time.
+/// Only the last `window` readings are kept, so memory doesn't grow with the input.
+#[derive(Debug, Clone)]
+pub struct SonarSweep {
+    recent: Window,
+    readings: u64,
+    increases: u64,
+}
+
+impl SonarSweep {
+    pub fn new(window: usize) -> Self {
+        SonarSweep { recent: Window::new(window), readings: 0, increases: 0 }
+    }
+
+    pub fn push(&mut self, depth: u32) {
+        if self.recent.push(depth).is_some_and(|first| depth > first) {
+            self.increases += 1;
+        }
+        self.readings += 1;
+    }
+
+    pub fn window(&self) -> usize {
+        self.recent.size()
+    }
+
+    pub fn readings(&self) -> u64 {
+        self.readings
+    }
+
+    pub fn increases(&self) -> u64 {
+        self.increases
+    }
+}
+
+/// Calls `on_depth` with every reading of the input, one line at a time, and returns the number of readings.
+/// Blank lines are skipped. The line buffer is reused, so arbitrarily long inputs run in constant memory.
+pub fn for_each_depth(mut reader: impl BufRead, mut on_depth: impl FnMut(u32)) -> Result<u64, AocError> {
+    let mut line = String::new();
+    let mut line_number = 0;
+    let mut readings = 0;
+    loop {
+        line.clear();
+        let read = reader.read_line(&mut line)
+            .map_err(|source| AocError::Io { path: "input stream".to_owned(), source })?;
+        if read == 0 {
+            return Ok(readings);
+        }
+        line_number += 1;
+        let text = line.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let depth = text.parse().map_err(|err| AocError::Parse {
+            line: line_number,
+            column: line.len() - line.trim_start().len() + 1,
+            message: format!("Can't parse {:?}: {}", text, err),
+        })?;
+        on_depth(depth);
+        readings += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day01::{Day01, for_each_depth, SonarSweep, Window, window_increases};
+    use crate::{AocError, Solution};
+
+    const SAMPLE_INPUT: &str = r#"
+                199
+                200
+                208
+                210
+                200
+                207
+                240
+                269
+                260
+                263
+        "#;
+
+    #[test]
+    fn solve_sample_part1() -> Result<(), AocError> {
+        let numbers = Day01::parse(SAMPLE_INPUT)?;
+        assert_eq!(Day01::part1(&numbers)?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn solve_sample_part2() -> Result<(), AocError> {
+        let numbers = Day01::parse(SAMPLE_INPUT)?;
+        assert_eq!(Day01::part2(&numbers)?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn any_window() -> Result<(), AocError> {
+        let numbers = Day01::parse(SAMPLE_INPUT)?;
+        let sums = |k: usize| -> Vec<u32> { numbers.windows(k).map(|w| w.iter().sum()).collect() };
+        for k in 1..=numbers.len() + 1 {
+            let expected = sums(k).windows(2).filter(|w| w[1] > w[0]).count() as u32;
+            assert_eq!(window_increases(&numbers, k), expected, "window {}", k);
+        }
+        assert_eq!(window_increases(&numbers, 0), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn streaming_sweep() -> Result<(), AocError> {
+        let mut sweeps = [SonarSweep::new(1), SonarSweep::new(3)];
+        let readings = for_each_depth(SAMPLE_INPUT.as_bytes(), |depth| {
+            sweeps.iter_mut().for_each(|sweep| sweep.push(depth));
+        })?;
+        assert_eq!(readings, 10);
+        assert_eq!((sweeps[0].increases(), sweeps[1].increases()), (7, 5));
+        Ok(())
+    }
+
+    #[test]
+    fn window_statistics() {
+        let mut window = Window::new(3);
+        assert_eq!([2, 4, 4].map(|d| window.push(d)), [None, None, None]);
+        assert_eq!(window.push(4), Some(2));
+        assert_eq!(window.push(5), Some(4));
+        assert_eq!(window.sum(), 13);
+        assert!((window.mean() - 13.0 / 3.0).abs() < 1e-9);
+        assert!((window.std_dev() - (2.0f64 / 9.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn streaming_parse_error() {
+        let result = for_each_depth("1\n\n  x2\n".as_bytes(), |_| ());
+        assert!(matches!(result, Err(AocError::Parse { line: 3, column: 3, .. })));
+    }
+}
@@ -0,0 +1,213 @@
+use std::cmp::Ordering;
+
+/// How readings compare with the reading `window` positions earlier,
+/// which is the same as how sums of `window` consecutive readings compare with the previous sum.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Trend {
+    pub window: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub unchanged: usize,
+}
+
+/// Consecutive readings that keep going in one direction. `start` is the index of the first reading.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Change between two consecutive readings, `index` is the index of the later one.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Change {
+    pub index: usize,
+    pub from: u32,
+    pub to: u32,
+}
+
+impl Change {
+    pub fn size(&self) -> u32 {
+        self.from.abs_diff(self.to)
+    }
+}
+
+/// Depth reading and its index.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Reading {
+    pub index: usize,
+    pub depth: u32,
+}
+
+/// Statistics of a depth profile. Everything that doesn't exist for too short inputs is optional,
+/// ties are resolved in favour of the earliest occurrence.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DepthReport {
+    pub readings: usize,
+    /// Comparison of consecutive readings.
+    pub changes: Trend,
+    pub longest_increasing: Option<Run>,
+    pub longest_decreasing: Option<Run>,
+    pub largest_rise: Option<Change>,
+    pub largest_drop: Option<Change>,
+    pub min: Option<Reading>,
+    pub max: Option<Reading>,
+    pub windows: Vec<Trend>,
+}
+
+impl DepthReport {
+    /// Report of the readings with trends over each of the windows.
+    pub fn new(depths: &[u32], windows: &[usize]) -> Self {
+        let changes: Vec<Change> = depths.windows(2).enumerate()
+            .map(|(idx, pair)| Change { index: idx + 1, from: pair[0], to: pair[1] })
+            .collect();
+        let largest = |ordering: Ordering| changes.iter()
+            .filter(|c| c.to.cmp(&c.from) == ordering)
+            .fold(None, |best: Option<Change>, c| match best {
+                Some(best) if best.size() >= c.size() => Some(best),
+                _ => Some(*c),
+            });
+        let reading = |(index, depth): (usize, &u32)| Reading { index, depth: *depth };
+
+        DepthReport {
+            readings: depths.len(),
+            changes: trend(depths, 1),
+            longest_increasing: longest_run(depths, Ordering::Greater),
+            longest_decreasing: longest_run(depths, Ordering::Less),
+            largest_rise: largest(Ordering::Greater),
+            largest_drop: largest(Ordering::Less),
+            min: depths.iter().enumerate().min_by_key(|(_, d)| **d).map(reading),
+            max: depths.iter().enumerate().rev().max_by_key(|(_, d)| **d).map(reading),
+            windows: windows.iter().map(|window| trend(depths, *window)).collect(),
+        }
+    }
+
+    pub fn table(&self) -> String {
+        let reading = |r: &Option<Reading>| r.map_or("-".to_owned(), |r| format!("{} (at {})", r.depth, r.index));
+        let run = |r: &Option<Run>| r.map_or("-".to_owned(), |r| format!("{} readings (from {})", r.length, r.start));
+        let change = |c: &Option<Change>| c.map_or("-".to_owned(), |c| {
+            format!("{} ({} -> {} at {})", c.size(), c.from, c.to, c.index)
+        });
+
+        let mut rows = vec![
+            ("Readings", self.readings.to_string()),
+            ("Increases", self.changes.increases.to_string()),
+            ("Decreases", self.changes.decreases.to_string()),
+            ("Unchanged", self.changes.unchanged.to_string()),
+            ("Longest increasing run", run(&self.longest_increasing)),
+            ("Longest decreasing run", run(&self.longest_decreasing)),
+            ("Largest rise", change(&self.largest_rise)),
+            ("Largest drop", change(&self.largest_drop)),
+            ("Min depth", reading(&self.min)),
+            ("Max depth", reading(&self.max)),
+        ];
+        let windows: Vec<(String, String)> = self.windows.iter()
+            .map(|t| (format!("Window {}", t.window),
+                      format!("{} up, {} down, {} unchanged", t.increases, t.decreases, t.unchanged)))
+            .collect();
+        rows.extend(windows.iter().map(|(name, value)| (name.as_str(), value.clone())));
+
+        rows.iter().map(|(name, value)| format!("{:<24}{}\n", name, value)).collect()
+    }
+
+    pub fn json(&self) -> String {
+        let optional = |value: Option<String>| value.unwrap_or_else(|| "null".to_owned());
+        let reading = |r: &Option<Reading>| optional(r.map(|r| format!(r#"{{"index": {}, "depth": {}}}"#, r.index, r.depth)));
+        let run = |r: &Option<Run>| optional(r.map(|r| format!(r#"{{"start": {}, "length": {}}}"#, r.start, r.length)));
+        let change = |c: &Option<Change>| optional(c.map(|c| {
+            format!(r#"{{"index": {}, "from": {}, "to": {}, "size": {}}}"#, c.index, c.from, c.to, c.size())
+        }));
+        let windows: Vec<String> = self.windows.iter()
+            .map(|t| format!(r#"    {{"window": {}, "increases": {}, "decreases": {}, "unchanged": {}}}"#,
+                             t.window, t.increases, t.decreases, t.unchanged))
+            .collect();
+        let windows = if windows.is_empty() { "[]".to_owned() } else { format!("[\n{}\n  ]", windows.join(",\n")) };
+
+        format!(r#"{{
+  "readings": {},
+  "increases": {},
+  "decreases": {},
+  "unchanged": {},
+  "longest_increasing_run": {},
+  "longest_decreasing_run": {},
+  "largest_rise": {},
+  "largest_drop": {},
+  "min_depth": {},
+  "max_depth": {},
+  "windows": {}
+}}
+"#,
+                self.readings, self.changes.increases, self.changes.decreases, self.changes.unchanged,
+                run(&self.longest_increasing), run(&self.longest_decreasing),
+                change(&self.largest_rise), change(&self.largest_drop),
+                reading(&self.min), reading(&self.max), windows)
+    }
+}
+
+/// Compares each reading with the one `window` positions earlier.
+pub fn trend(depths: &[u32], window: usize) -> Trend {
+    let mut trend = Trend { window, increases: 0, decreases: 0, unchanged: 0 };
+    for (first, last) in depths.iter().zip(depths.iter().skip(window)) {
+        match last.cmp(first) {
+            Ordering::Greater => trend.increases += 1,
+            Ordering::Less => trend.decreases += 1,
+            Ordering::Equal => trend.unchanged += 1,
+        }
+    }
+    trend
+}
+
+/// Longest run in which every reading compares with the previous one as `direction`.
+/// A single reading is a run of length 1.
+fn longest_run(depths: &[u32], direction: Ordering) -> Option<Run> {
+    let mut longest: Option<Run> = None;
+    let mut current = Run { start: 0, length: 0 };
+    for (idx, depth) in depths.iter().enumerate() {
+        if idx > 0 && depth.cmp(&depths[idx - 1]) == direction {
+            current.length += 1;
+        } else {
+            current = Run { start: idx, length: 1 };
+        }
+        if longest.is_none_or(|l| current.length > l.length) {
+            longest = Some(current);
+        }
+    }
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day01::report::{Change, DepthReport, Reading, Run, Trend};
+
+    const SAMPLE: [u32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn sample_report() {
+        let report = DepthReport::new(&SAMPLE, &[3]);
+        assert_eq!(report.readings, 10);
+        assert_eq!(report.changes, Trend { window: 1, increases: 7, decreases: 2, unchanged: 0 });
+        assert_eq!(report.longest_increasing, Some(Run { start: 0, length: 4 }));
+        assert_eq!(report.longest_decreasing, Some(Run { start: 3, length: 2 }));
+        assert_eq!(report.largest_rise, Some(Change { index: 6, from: 207, to: 240 }));
+        assert_eq!(report.largest_drop, Some(Change { index: 4, from: 210, to: 200 }));
+        assert_eq!(report.min, Some(Reading { index: 0, depth: 199 }));
+        assert_eq!(report.max, Some(Reading { index: 7, depth: 269 }));
+        assert_eq!(report.windows, vec![Trend { window: 3, increases: 5, decreases: 1, unchanged: 1 }]);
+    }
+
+    #[test]
+    fn ties_take_first() {
+        let report = DepthReport::new(&[5, 1, 5, 1, 5], &[]);
+        assert_eq!(report.min, Some(Reading { index: 1, depth: 1 }));
+        assert_eq!(report.max, Some(Reading { index: 0, depth: 5 }));
+        assert_eq!(report.largest_rise, Some(Change { index: 2, from: 1, to: 5 }));
+        assert_eq!(report.longest_increasing, Some(Run { start: 1, length: 2 }));
+    }
+
+    #[test]
+    fn empty_report() {
+        let report = DepthReport::new(&[], &[1]);
+        assert_eq!(report.longest_increasing, None);
+        assert_eq!(report.min, None);
+        assert!(report.json().contains(r#""largest_drop": null"#));
+    }
+}
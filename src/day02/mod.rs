@@ -0,0 +1,91 @@
+use crate::{AocError, Part, Solution};
+use crate::day02::model::Step;
+use crate::day02::navigation::navigate;
+
+pub mod model;
+pub mod navigation;
+pub mod repl;
+pub mod script;
+pub mod trajectory;
+
+pub use model::Command;
+pub use navigation::{Model, Policy, Position, run_aim_commands, run_position_commands, Submarine};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Step>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(steps: &Self::Input) -> Result<Self::Answer1, AocError> {
+        part1(steps)
+    }
+
+    fn part2(steps: &Self::Input) -> Result<Self::Answer2, AocError> {
+        part2(steps)
+    }
+}
+
+/// Puzzle input is a script without comments and repeats, see [script].
+pub fn parse(input: &str) -> Result<Vec<Step>, AocError> {
+    script::parse_commands(input)
+}
+
+pub fn part1(steps: &[Step]) -> Result<i64, AocError> {
+    answer(Model::for_part(Part::One), steps)
+}
+
+pub fn part2(steps: &[Step]) -> Result<i64, AocError> {
+    answer(Model::for_part(Part::Two), steps)
+}
+
+/// Going above the surface is an error pointing at the step, see [navigate].
+fn answer(model: Model, steps: &[Step]) -> Result<i64, AocError> {
+    let mut navigation = model.start();
+    navigate(navigation.as_mut(), steps)?;
+    navigation.answer()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day02::Day02;
+    use crate::{AocError, Solution};
+
+    const SAMPLE_INPUT: &str = r#"
+        forward 5
+        down 5
+        forward 8
+        up 3
+        down 8
+        forward 2
+        "#;
+
+    #[test]
+    fn solve_sample_part1() -> Result<(), AocError> {
+        let commands = Day02::parse(SAMPLE_INPUT)?;
+        assert_eq!(150, Day02::part1(&commands)?);
+        Ok(())
+    }
+
+    #[test]
+    fn solve_sample_part2() -> Result<(), AocError> {
+        let commands = Day02::parse(SAMPLE_INPUT)?;
+        assert_eq!(900, Day02::part2(&commands)?);
+        Ok(())
+    }
+
+    #[test]
+    fn surfacing_is_an_error() {
+        let commands = Day02::parse("forward 5\ndown 2\nup 3\n").unwrap();
+        match Day02::part1(&commands) {
+            Err(AocError::Invalid(message)) => assert!(message.starts_with("Line 3: 'up 3' takes depth to -1"), "{}", message),
+            other => panic!("Expected error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use nom::error::context;
+
+use crate::day02::model::Command::{Back, Down, Forward, Reset, Up};
+use crate::day02::script;
+use crate::parse::parse_all;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Command {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+    /// Moves backwards, i.e. decreases the horizontal position.
+    Back(u32),
+    /// Returns to the starting position.
+    Reset,
+}
+
+/// Command together with the line of the script it comes from, counted from 1.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Step {
+    pub line: usize,
+    pub command: Command,
+}
+
+impl Step {
+    pub fn new(line: usize, command: Command) -> Self {
+        Step { line, command }
+    }
+
+    /// Steps numbered by their position, for commands that don't come from a script.
+    pub fn numbered(commands: &[Command]) -> Vec<Step> {
+        commands.iter().enumerate().map(|(idx, command)| Step::new(idx + 1, *command)).collect()
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Forward(val) => write!(f, "forward {}", val),
+            Down(val) => write!(f, "down {}", val),
+            Up(val) => write!(f, "up {}", val),
+            Back(val) => write!(f, "back {}", val),
+            Reset => write!(f, "reset"),
+        }
+    }
+}
+
+/// Same grammar as commands in scripts, see [script::command].
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(s, context("command (forward, down, up, back or reset)", script::command))
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day02::model::Command;
+
+    #[test]
+    fn parse_forward() {
+        let line = "forward 10";
+        let expected = Command::Forward(10);
+        let result = line.parse::<Command>();
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_down() {
+        let line = "down 15";
+        let expected = Command::Down(15);
+        let result = line.parse::<Command>();
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_up() {
+        let line = "up 20";
+        let expected = Command::Up(20);
+        let result = line.parse::<Command>();
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_back_and_reset() {
+        assert_eq!(Ok(Command::Back(3)), "back 3".parse::<Command>());
+        assert_eq!(Ok(Command::Reset), "reset".parse::<Command>());
+        assert!("reset 1".parse::<Command>().is_err());
+        assert_eq!(Ok(Command::Forward(5)), "forward \t 5".parse::<Command>());
+    }
+}
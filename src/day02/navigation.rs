@@ -0,0 +1,385 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::day02::model::{Command, Step};
+use crate::day02::model::Command::{Back, Down, Forward, Reset, Up};
+use crate::{AocError, Part};
+
+/// Interpretation of the commands, i.e. how each of them moves the submarine.
+/// All interpretations share the same commands and parser, a new one only has to implement this trait.
+///
+/// State is signed, so that going above the surface can be detected and reported by [pilot]
+/// instead of wrapping around. Arithmetic is checked, a value that doesn't fit is an [Overflow].
+pub trait Navigation {
+    fn execute(&mut self, command: &Command) -> Result<(), Overflow>;
+
+    fn depth(&self) -> i64;
+
+    fn horizontal(&self) -> i64;
+
+    /// Aim of models that have one.
+    fn aim(&self) -> Option<i64> {
+        None
+    }
+
+    /// Values that shouldn't go below zero, with their names.
+    fn levels(&self) -> Vec<(&'static str, i64)> {
+        vec![("depth", self.depth())]
+    }
+
+    /// Brings every negative level back to zero.
+    fn clamp(&mut self);
+
+    /// Answer to the puzzle, final depth multiplied by final horizontal position.
+    fn answer(&self) -> Result<i64, AocError> {
+        self.depth().checked_mul(self.horizontal())
+            .ok_or_else(|| AocError::Invalid(format!("Answer overflows: {} * {}", self.depth(), self.horizontal())))
+    }
+}
+
+/// Named value of the state that doesn't fit into its type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Overflow(pub &'static str);
+
+/// What to do when a command takes a level, e.g. depth or aim, below zero.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Policy {
+    /// Stop with an error pointing at the command.
+    Error,
+    /// Set the level to zero, i.e. stop at the surface.
+    Clamp,
+    /// Keep the negative value.
+    Allow,
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Policy::Error),
+            "clamp" => Ok(Policy::Clamp),
+            "allow" => Ok(Policy::Allow),
+            other => Err(format!("Unknown policy: {}", other)),
+        }
+    }
+}
+
+/// Problem caused by a single step of the script.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Diagnostic {
+    pub step: Step,
+    pub issue: Issue,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Issue {
+    /// The level went below zero, e.g. the submarine surfaced above water.
+    Negative { level: &'static str, value: i64 },
+    Overflow { value: &'static str },
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.issue {
+            Issue::Negative { level, value } => {
+                let limit = if level == "depth" { "above the surface" } else { "below zero" };
+                write!(f, "Line {}: '{}' takes {} to {}, {}", self.step.line, self.step.command, level, value, limit)
+            }
+            Issue::Overflow { value } =>
+                write!(f, "Line {}: '{}' overflows {}", self.step.line, self.step.command, value),
+        }
+    }
+}
+
+/// Executes the steps in order and returns the steps that took a level below zero, handled according to the policy.
+/// Only the step crossing zero is reported, not the ones keeping the level negative after it.
+/// An overflow is always an error, just like a negative level under [Policy::Error].
+pub fn pilot<N: Navigation + ?Sized>(model: &mut N, steps: &[Step], policy: Policy) -> Result<Vec<Diagnostic>, AocError> {
+    pilot_each(model, steps, policy, |_, _| {})
+}
+
+/// Same as [pilot], calling `on_step` with the state after each step, i.e. after the policy was applied.
+pub fn pilot_each<N, F>(model: &mut N, steps: &[Step], policy: Policy, mut on_step: F) -> Result<Vec<Diagnostic>, AocError>
+    where N: Navigation + ?Sized,
+          F: FnMut(&Step, &N)
+{
+    let mut diagnostics = Vec::new();
+    for step in steps {
+        let before = model.levels();
+        if let Err(Overflow(value)) = model.execute(&step.command) {
+            return Err(AocError::Invalid(Diagnostic { step: *step, issue: Issue::Overflow { value } }.to_string()));
+        }
+        let negative: Vec<Diagnostic> = model.levels().into_iter()
+            .zip(before)
+            .filter(|((_, value), (_, previous))| *value < 0 && *previous >= 0)
+            .map(|(level, _)| level)
+            .map(|(level, value)| Diagnostic { step: *step, issue: Issue::Negative { level, value } })
+            .collect();
+        match (policy, negative.first()) {
+            (_, None) => {}
+            (Policy::Error, Some(diagnostic)) => return Err(AocError::Invalid(diagnostic.to_string())),
+            (Policy::Clamp, Some(_)) => model.clamp(),
+            (Policy::Allow, Some(_)) => {}
+        }
+        on_step(step, model);
+        diagnostics.extend(negative);
+    }
+    Ok(diagnostics)
+}
+
+/// Executes the steps, stopping at the first one that takes a level below zero or overflows.
+pub fn navigate<N: Navigation + ?Sized>(model: &mut N, steps: &[Step]) -> Result<(), AocError> {
+    pilot(model, steps, Policy::Error).map(|_| ())
+}
+
+/// Built-in interpretations, selectable by part or by name.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Model {
+    /// Up and down change the depth directly (part 1).
+    Position,
+    /// Up and down change the aim, forward dives along it (part 2).
+    Aim,
+}
+
+impl Model {
+    pub const ALL: [Model; 2] = [Model::Position, Model::Aim];
+
+    pub fn for_part(part: Part) -> Self {
+        match part {
+            Part::One => Model::Position,
+            Part::Two => Model::Aim,
+        }
+    }
+
+    /// The model at the starting position.
+    pub fn start(&self) -> Box<dyn Navigation> {
+        match self {
+            Model::Position => Box::new(Position::new(0, 0)),
+            Model::Aim => Box::new(Submarine::new(0, 0, 0)),
+        }
+    }
+}
+
+impl Display for Model {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Model::Position => write!(f, "position"),
+            Model::Aim => write!(f, "aim"),
+        }
+    }
+}
+
+impl FromStr for Model {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "position" => Ok(Model::Position),
+            "aim" => Ok(Model::Aim),
+            other => Err(format!("Unknown navigation model: {}", other)),
+        }
+    }
+}
+
+fn add(value: i64, delta: i64, name: &'static str) -> Result<i64, Overflow> {
+    value.checked_add(delta).ok_or(Overflow(name))
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Position {
+    pub depth: i64,
+    pub horizontal: i64,
+}
+
+impl Position {
+    pub fn new(depth: i64, horizontal: i64) -> Position {
+        Position { depth, horizontal }
+    }
+}
+
+impl Navigation for Position {
+    fn execute(&mut self, command: &Command) -> Result<(), Overflow> {
+        match *command {
+            Forward(val) => self.horizontal = add(self.horizontal, val as i64, "horizontal")?,
+            Up(val) => self.depth = add(self.depth, -(val as i64), "depth")?,
+            Down(val) => self.depth = add(self.depth, val as i64, "depth")?,
+            Back(val) => self.horizontal = add(self.horizontal, -(val as i64), "horizontal")?,
+            Reset => *self = Position::new(0, 0),
+        };
+        Ok(())
+    }
+
+    fn depth(&self) -> i64 {
+        self.depth
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.horizontal
+    }
+
+    fn clamp(&mut self) {
+        self.depth = self.depth.max(0);
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Submarine {
+    pub depth: i64,
+    pub horizontal: i64,
+    pub aim: i64,
+}
+
+impl Submarine {
+    pub fn new(depth: i64, horizontal: i64, aim: i64) -> Self {
+        Submarine {
+            depth,
+            horizontal,
+            aim,
+        }
+    }
+}
+
+impl Navigation for Submarine {
+    fn execute(&mut self, command: &Command) -> Result<(), Overflow> {
+        match *command {
+            Forward(val) => {
+                self.horizontal = add(self.horizontal, val as i64, "horizontal")?;
+                let dive = self.aim.checked_mul(val as i64).ok_or(Overflow("depth"))?;
+                self.depth = add(self.depth, dive, "depth")?;
+            }
+            Up(val) => self.aim = add(self.aim, -(val as i64), "aim")?,
+            Down(val) => self.aim = add(self.aim, val as i64, "aim")?,
+            Back(val) => self.horizontal = add(self.horizontal, -(val as i64), "horizontal")?,
+            Reset => *self = Submarine::new(0, 0, 0),
+        };
+        Ok(())
+    }
+
+    fn depth(&self) -> i64 {
+        self.depth
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.horizontal
+    }
+
+    fn aim(&self) -> Option<i64> {
+        Some(self.aim)
+    }
+
+    fn levels(&self) -> Vec<(&'static str, i64)> {
+        vec![("aim", self.aim), ("depth", self.depth)]
+    }
+
+    fn clamp(&mut self) {
+        self.aim = self.aim.max(0);
+        self.depth = self.depth.max(0);
+    }
+}
+
+/// Runs the commands with the position model, see [navigate].
+pub fn run_position_commands(commands: &[Command]) -> Result<Position, AocError> {
+    let mut position = Position::new(0, 0);
+    navigate(&mut position, &Step::numbered(commands))?;
+    Ok(position)
+}
+
+/// Runs the commands with the aim model, see [navigate].
+pub fn run_aim_commands(commands: &[Command]) -> Result<Submarine, AocError> {
+    let mut submarine = Submarine::new(0, 0, 0);
+    navigate(&mut submarine, &Step::numbered(commands))?;
+    Ok(submarine)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AocError;
+    use crate::day02::model::{Command, Step};
+    use crate::day02::model::Command::{Down, Forward, Up};
+    use crate::day02::navigation::{Issue, Model, navigate, Navigation, Overflow, pilot, Policy, Position, Submarine};
+
+    /// Forward moves twice as far, up and down are ignored.
+    struct Towed {
+        horizontal: i64,
+    }
+
+    impl Navigation for Towed {
+        fn execute(&mut self, command: &Command) -> Result<(), Overflow> {
+            if let Forward(val) = command {
+                self.horizontal += 2 * *val as i64;
+            }
+            Ok(())
+        }
+
+        fn depth(&self) -> i64 {
+            1
+        }
+
+        fn horizontal(&self) -> i64 {
+            self.horizontal
+        }
+
+        fn clamp(&mut self) {}
+    }
+
+    #[test]
+    fn custom_model() {
+        let mut towed = Towed { horizontal: 0 };
+        navigate(&mut towed, &Step::numbered(&[Forward(5), Down(5), Forward(8), Up(3)])).unwrap();
+        assert_eq!(towed.answer().unwrap(), 26);
+    }
+
+    #[test]
+    fn built_in_models() {
+        let steps = Step::numbered(&[Forward(5), Down(5), Forward(8), Up(3), Down(8), Forward(2)]);
+        let answers: Vec<i64> = Model::ALL.iter()
+            .map(|model| {
+                let mut navigation = model.start();
+                navigate(navigation.as_mut(), &steps).unwrap();
+                navigation.answer().unwrap()
+            })
+            .collect();
+        assert_eq!(answers, vec![150, 900]);
+        assert_eq!("aim".parse(), Ok(Model::Aim));
+    }
+
+    const SURFACING: [Command; 5] = [Down(2), Forward(1), Up(5), Forward(3), Down(1)];
+
+    #[test]
+    fn error_policy_points_at_line() {
+        let steps = vec![Step::new(3, Down(2)), Step::new(4, Up(5)), Step::new(7, Down(9))];
+        match pilot(&mut Submarine::new(0, 0, 0), &steps, Policy::Error) {
+            Err(AocError::Invalid(message)) => assert_eq!(message, "Line 4: 'up 5' takes aim to -3, below zero"),
+            other => panic!("Expected error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn clamp_policy_stops_at_surface() {
+        let mut submarine = Submarine::new(0, 0, 0);
+        let diagnostics = pilot(&mut submarine, &Step::numbered(&SURFACING), Policy::Clamp).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].step.line, 3);
+        assert_eq!(submarine, Submarine::new(2, 4, 1));
+    }
+
+    #[test]
+    fn allow_policy_reports_crossing_steps() {
+        let mut submarine = Submarine::new(0, 0, 0);
+        let diagnostics = pilot(&mut submarine, &Step::numbered(&SURFACING), Policy::Allow).unwrap();
+        let lines: Vec<usize> = diagnostics.iter().map(|d| d.step.line).collect();
+        assert_eq!(lines, vec![3, 4]);
+        assert_eq!(diagnostics[1].issue, Issue::Negative { level: "depth", value: -7 });
+        assert_eq!(submarine, Submarine::new(-7, 4, -2));
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let steps = Step::numbered(&[Down(u32::MAX), Forward(1), Forward(u32::MAX)]);
+        let result = pilot(&mut Submarine::new(0, 0, 0), &steps, Policy::Allow);
+        assert!(matches!(result, Err(AocError::Invalid(message)) if message == "Line 3: 'forward 4294967295' overflows depth"));
+        let mut position = Position::new(0, 0);
+        assert!(navigate(&mut position, &Step::numbered(&[Up(1)])).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use crate::AocError;
+use crate::day02::model::{Command, Step};
+use crate::day02::navigation::{Navigation, pilot, Policy, Position, Submarine};
+use crate::day02::script;
+use crate::input::read_input;
+
+const HELP: &str = "Commands: forward N, down N, up N, back N, reset, undo, state, load <file>, save <file>, help, quit";
+
+/// Commands entered so far with the state of both interpretations after each of them.
+/// Commands that would take depth above the surface or aim below zero are rejected, see [Policy::Error].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Session {
+    steps: Vec<Step>,
+    /// State before each of the steps, for undo.
+    previous: Vec<(Position, Submarine)>,
+    position: Position,
+    submarine: Submarine,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Session { steps: Vec::new(), previous: Vec::new(), position: Position::new(0, 0), submarine: Submarine::new(0, 0, 0) }
+    }
+}
+
+impl Session {
+    /// Session that has executed the steps, or the error of the first step that can't be executed.
+    pub fn from_steps(steps: &[Step]) -> Result<Self, AocError> {
+        let mut session = Session::default();
+        for step in steps {
+            session.execute(*step)?;
+        }
+        Ok(session)
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn submarine(&self) -> &Submarine {
+        &self.submarine
+    }
+
+    /// Executes the step with both models, changing nothing if either of them rejects it.
+    pub fn execute(&mut self, step: Step) -> Result<(), AocError> {
+        let (mut position, mut submarine) = (self.position, self.submarine);
+        pilot(&mut position, &[step], Policy::Error)?;
+        pilot(&mut submarine, &[step], Policy::Error)?;
+        self.previous.push((self.position, self.submarine));
+        self.steps.push(step);
+        (self.position, self.submarine) = (position, submarine);
+        Ok(())
+    }
+
+    /// Takes back the last command, returning it.
+    pub fn undo(&mut self) -> Option<Command> {
+        let step = self.steps.pop()?;
+        (self.position, self.submarine) = self.previous.pop()?;
+        Some(step.command)
+    }
+
+    /// Commands one per line, which can be loaded again.
+    pub fn script(&self) -> String {
+        self.steps.iter().map(|step| format!("{}\n", step.command)).collect()
+    }
+
+    /// State of both models, one per line.
+    pub fn state(&self) -> String {
+        let answer = |model: &dyn Navigation| model.answer().map_or("overflows".to_owned(), |a| a.to_string());
+        let (position, submarine) = (&self.position, &self.submarine);
+        format!("position: horizontal {}, depth {}, answer {}\naim:      horizontal {}, depth {}, aim {}, answer {}",
+                position.horizontal, position.depth, answer(position),
+                submarine.horizontal, submarine.depth, submarine.aim, answer(submarine))
+    }
+}
+
+enum Action {
+    Print(String),
+    Quit,
+}
+
+/// Interactive session reading commands from `input` and answering to `output`, until `quit` or the end of input.
+/// Each command shows the state of both interpretations right away; errors are printed and the session goes on.
+pub fn repl(mut session: Session, input: impl BufRead, mut output: impl Write) -> Result<Session, AocError> {
+    let mut lines = input.lines().enumerate();
+    write(&mut output, &format!("{}\n", HELP))?;
+    loop {
+        write(&mut output, "> ")?;
+        let (idx, line) = match lines.next() {
+            Some((idx, line)) => (idx, line.map_err(|source| AocError::Io { path: "REPL input".to_owned(), source })?),
+            None => {
+                write(&mut output, "\n")?;
+                break;
+            }
+        };
+        match evaluate(&mut session, idx + 1, line.trim()) {
+            Ok(Action::Print(text)) if text.is_empty() => {}
+            Ok(Action::Print(text)) => write(&mut output, &format!("{}\n", text))?,
+            Ok(Action::Quit) => break,
+            Err(err) => write(&mut output, &format!("Error: {}\n", err))?,
+        }
+    }
+    Ok(session)
+}
+
+fn evaluate(session: &mut Session, line: usize, text: &str) -> Result<Action, String> {
+    // File names are taken as typed, '#' included; everything else may end with a comment like scripts.
+    let text = match split_word(text) {
+        ("load" | "save", _) => text,
+        _ => text.split('#').next().unwrap_or_default().trim(),
+    };
+    let (word, argument) = split_word(text);
+    let reply = match (word, argument) {
+        ("", _) => String::new(),
+        ("quit" | "exit", "") => return Ok(Action::Quit),
+        ("help", "") => HELP.to_owned(),
+        ("state", "") => format!("{} commands\n{}", session.steps().len(), session.state()),
+        ("undo", "") => match session.undo() {
+            Some(command) => format!("Undid '{}'\n{}", command, session.state()),
+            None => "Nothing to undo".to_owned(),
+        },
+        ("load", path) if !path.is_empty() => {
+            let steps = script::parse_commands(&read_input(Path::new(path)).map_err(|e| e.to_string())?)
+                .map_err(|e| format!("{}: {}", path, e))?;
+            *session = Session::from_steps(&steps).map_err(|e| format!("{}: {}", path, e))?;
+            format!("Loaded {} commands from {}\n{}", steps.len(), path, session.state())
+        }
+        ("save", path) if !path.is_empty() => {
+            fs::write(path, session.script()).map_err(|e| format!("Can't write {}: {}", path, e))?;
+            format!("Saved {} commands to {}", session.steps().len(), path)
+        }
+        _ => {
+            // Same grammar as scripts loaded from files, see [script::command].
+            let command = text.parse::<Command>()?;
+            session.execute(Step::new(line, command)).map_err(|e| e.to_string())?;
+            session.state()
+        }
+    };
+    Ok(Action::Print(reply))
+}
+
+fn split_word(text: &str) -> (&str, &str) {
+    text.split_once(char::is_whitespace).map_or((text, ""), |(word, argument)| (word, argument.trim()))
+}
+
+fn write(output: &mut impl Write, text: &str) -> Result<(), AocError> {
+    output.write_all(text.as_bytes())
+        .and_then(|_| output.flush())
+        .map_err(|source| AocError::Io { path: "REPL output".to_owned(), source })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::day02::model::Command::{Down, Forward, Up};
+    use crate::day02::navigation::{Position, Submarine};
+    use crate::day02::repl::{repl, Session};
+
+    fn transcript(input: &str) -> (Vec<String>, Session) {
+        let mut output = Vec::new();
+        let session = repl(Session::default(), input.as_bytes(), &mut output).unwrap();
+        let lines = String::from_utf8(output).unwrap().lines().map(|l| l.to_owned()).collect();
+        (lines, session)
+    }
+
+    #[test]
+    fn commands_update_both_models() {
+        let (lines, session) = transcript("forward 5\ndown 5\nforward 8\n");
+        assert_eq!(session.position(), &Position::new(5, 13));
+        assert_eq!(session.submarine(), &Submarine::new(40, 13, 5));
+        assert_eq!(lines[5], "> position: horizontal 13, depth 5, answer 65");
+        assert_eq!(lines[6], "aim:      horizontal 13, depth 40, aim 5, answer 520");
+    }
+
+    #[test]
+    fn same_syntax_as_scripts() {
+        let (lines, session) = transcript("forward  5\ndown\t3 # dive\nforward \t 2\nundo\t\n");
+        assert!(!lines.iter().any(|l| l.contains("Error")), "{:?}", lines);
+        assert_eq!(session.steps().iter().map(|s| s.command).collect::<Vec<_>>(), vec![Forward(5), Down(3)]);
+    }
+
+    #[test]
+    fn undo_and_errors() {
+        let (lines, session) = transcript("down 2\nforward 3\nup 3\nsideways 1\nundo\nundo\nundo\nundo\nforward 1\nquit\nforward 1");
+        assert!(lines.contains(&"> Error: Invalid input: Line 3: 'up 3' takes depth to -1, above the surface".to_owned()));
+        assert!(lines.contains(&"> Error: Parse error at line 1, column 1: expected command (forward, down, up, back or reset)".to_owned()));
+        assert!(lines.contains(&"> Undid 'forward 3'".to_owned()));
+        assert!(lines.contains(&"> Nothing to undo".to_owned()));
+        assert_eq!(session.steps().iter().map(|s| s.command).collect::<Vec<_>>(), vec![Forward(1)]);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = env::temp_dir().join(format!("aoc2021-repl-{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        let (_, saved) = transcript(&format!("down 4\nforward 2 # dive\nup 1\nsave {}\n", path));
+        assert_eq!(fs::read_to_string(path).unwrap(), "down 4\nforward 2\nup 1\n");
+
+        let (lines, loaded) = transcript(&format!("forward 9\nload {}\nstate\n", path));
+        fs::remove_file(path).unwrap();
+        assert_eq!(loaded, saved);
+        assert_eq!(loaded.steps()[2].command, Up(1));
+        assert!(lines.iter().any(|l| l == "> 3 commands"), "{:?}", lines);
+        assert_eq!(saved.steps()[0].command, Down(4));
+    }
+
+    #[test]
+    fn file_names_keep_hash() {
+        let path = env::temp_dir().join(format!("aoc2021-repl-{}#saved.txt", std::process::id()));
+        let (lines, _) = transcript(&format!("forward 3\nsave {}\n", path.to_str().unwrap()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "forward 3\n", "{:?}", lines);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,276 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, line_ending, multispace1, not_line_ending, space0, space1, u32 as p_u32};
+use nom::combinator::{cut, eof, map, peek, recognize, value};
+use nom::error::context;
+use nom::multi::many0_count;
+use nom::sequence::{pair, preceded, tuple};
+
+use crate::AocError;
+use crate::day02::model::{Command, Step};
+use crate::day02::model::Command::{Back, Down, Forward, Reset, Up};
+use crate::day02::navigation::{Diagnostic, Navigation, pilot, Policy};
+use crate::parse::{parse_all, ParseResult};
+
+/// Scripts expanding to (or running) more commands than this are rejected, so that nested repeats can't exhaust
+/// memory or run for ages.
+pub const MAX_COMMANDS: usize = 10_000_000;
+
+/// Statement of a manoeuvre script.
+///
+/// Scripts have one statement per line (or several separated by `;`), `#` starts a comment and
+/// `repeat N { ... }` repeats the enclosed statements, e.g.
+/// ```text
+/// down 5      # dive
+/// repeat 3 {
+///     forward 2; up 1
+/// }
+/// ```
+/// Commands keep the line they are on, so that problems can be reported against the script.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Statement {
+    Command(Step),
+    Repeat(u32, Vec<Statement>),
+}
+
+pub fn parse_script(input: &str) -> Result<Vec<Statement>, AocError> {
+    let lines = Lines::new(input);
+    parse_all(input, |s| statements(&lines, s))
+}
+
+/// Parses the script and expands repeats into the plain list of steps.
+pub fn parse_commands(input: &str) -> Result<Vec<Step>, AocError> {
+    expand(&parse_script(input)?, MAX_COMMANDS)
+}
+
+/// Plain list of steps the statements stand for, at most `limit` of them.
+pub fn expand(statements: &[Statement], limit: usize) -> Result<Vec<Step>, AocError> {
+    fn push_all(statements: &[Statement], steps: &mut Vec<Step>, limit: usize) -> Result<(), AocError> {
+        for statement in statements {
+            match statement {
+                Statement::Command(step) => {
+                    if steps.len() == limit {
+                        return Err(AocError::Invalid(format!("Script expands to more than {} commands", limit)));
+                    }
+                    steps.push(*step);
+                }
+                Statement::Repeat(times, body) => {
+                    for _ in 0..*times {
+                        push_all(body, steps, limit)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    let mut steps = Vec::new();
+    push_all(statements, &mut steps, limit)?;
+    Ok(steps)
+}
+
+/// Executes the statements without expanding them, at most `limit` commands, so even huge repeat counts need no memory.
+/// Negative levels are handled according to the policy, see [pilot].
+pub fn run<N: Navigation + ?Sized>(model: &mut N, statements: &[Statement], policy: Policy, limit: usize) -> Result<Vec<Diagnostic>, AocError> {
+    fn run_all<N: Navigation + ?Sized>(model: &mut N, statements: &[Statement], policy: Policy, budget: &mut usize,
+                                        diagnostics: &mut Vec<Diagnostic>, limit: usize) -> Result<(), AocError> {
+        for statement in statements {
+            match statement {
+                Statement::Command(step) => {
+                    if *budget == 0 {
+                        return Err(AocError::Invalid(format!("Script runs more than {} commands", limit)));
+                    }
+                    *budget -= 1;
+                    diagnostics.extend(pilot(model, &[*step], policy)?);
+                }
+                Statement::Repeat(times, body) => {
+                    for _ in 0..*times {
+                        run_all(model, body, policy, budget, diagnostics, limit)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    let (mut budget, mut diagnostics) = (limit, Vec::new());
+    run_all(model, statements, policy, &mut budget, &mut diagnostics, limit)?;
+    Ok(diagnostics)
+}
+
+/// Start of every line of the script, to number the line of each command as it is parsed.
+struct Lines<'a> {
+    input: &'a str,
+    starts: Vec<usize>,
+}
+
+impl<'a> Lines<'a> {
+    fn new(input: &'a str) -> Self {
+        let starts = std::iter::once(0).chain(input.match_indices('\n').map(|(idx, _)| idx + 1)).collect();
+        Lines { input, starts }
+    }
+
+    /// Line of the input where `rest`, what is left of the input, starts.
+    fn of(&self, rest: &str) -> usize {
+        let offset = self.input.len() - rest.len();
+        self.starts.partition_point(|&start| start <= offset)
+    }
+}
+
+fn statements<'a>(lines: &Lines, mut s: &'a str) -> ParseResult<'a, Vec<Statement>> {
+    let mut items = Vec::new();
+    loop {
+        let (rest, _) = gap(s)?;
+        if rest.is_empty() || rest.starts_with('}') {
+            return Ok((rest, items));
+        }
+        let (rest, item) = statement(lines, rest)?;
+        let (rest, _) = end_of_statement(rest)?;
+        items.push(item);
+        s = rest;
+    }
+}
+
+fn statement<'a>(lines: &Lines, s: &'a str) -> ParseResult<'a, Statement> {
+    context("command (forward, down, up, back, reset or repeat)", alt((
+        map(command, |command| Statement::Command(Step::new(lines.of(s), command))),
+        |s| repeat(lines, s),
+    )))(s)
+}
+
+pub fn command(s: &str) -> ParseResult<'_, Command> {
+    let with_value = |keyword, command: fn(u32) -> Command| {
+        map(preceded(tag(keyword), cut(preceded(space1, number))), command)
+    };
+    alt((
+        with_value("forward", Forward),
+        with_value("down", Down),
+        with_value("up", Up),
+        with_value("back", Back),
+        value(Reset, tag("reset")),
+    ))(s)
+}
+
+fn repeat<'a>(lines: &Lines, s: &'a str) -> ParseResult<'a, Statement> {
+    let (s, (times, _, body, _, _)) = preceded(tag("repeat"), cut(tuple((
+        preceded(space1, number),
+        preceded(space0, context("'{'", char('{'))),
+        |s| statements(lines, s),
+        gap,
+        context("'}'", char('}')),
+    ))))(s)?;
+    Ok((s, Statement::Repeat(times, body)))
+}
+
+fn number(s: &str) -> ParseResult<'_, u32> {
+    context("number", p_u32)(s)
+}
+
+fn comment(s: &str) -> ParseResult<'_, &str> {
+    recognize(pair(char('#'), not_line_ending))(s)
+}
+
+/// Whitespace, line breaks and comments between statements.
+fn gap(s: &str) -> ParseResult<'_, ()> {
+    value((), many0_count(alt((multispace1, comment))))(s)
+}
+
+fn end_of_statement(s: &str) -> ParseResult<'_, ()> {
+    value((), preceded(space0, context("end of line", alt((
+        comment,
+        line_ending,
+        tag(";"),
+        peek(tag("}")),
+        eof,
+    )))))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AocError;
+    use crate::day02::model::Step;
+    use crate::day02::model::Command::{Back, Down, Forward, Reset, Up};
+    use crate::day02::navigation::{navigate, Policy, Position, Submarine};
+    use crate::day02::script::{expand, parse_commands, parse_script, run, Statement};
+
+    const SCRIPT: &str = "# warm up
+down 5\t\t# dive
+repeat 2 {
+    forward  3; up 1
+    repeat 3 { back 1 }
+}
+
+reset
+forward 1";
+
+    #[test]
+    fn nested_repeats() {
+        let statements = parse_script(SCRIPT).unwrap();
+        assert_eq!(statements[1], Statement::Repeat(2, vec![
+            Statement::Command(Step::new(4, Forward(3))),
+            Statement::Command(Step::new(4, Up(1))),
+            Statement::Repeat(3, vec![Statement::Command(Step::new(5, Back(1)))]),
+        ]));
+        let steps = expand(&statements, 100).unwrap();
+        assert_eq!(steps.len(), 1 + 2 * 5 + 2);
+        assert_eq!(&steps[..3], &[Step::new(2, Down(5)), Step::new(4, Forward(3)), Step::new(4, Up(1))]);
+        assert_eq!(&steps[11..], &[Step::new(8, Reset), Step::new(9, Forward(1))]);
+    }
+
+    #[test]
+    fn run_matches_expanded() {
+        let script = "down 9\nrepeat 4 { forward 2\nrepeat 2 { back 1; up 1 } }";
+        let statements = parse_script(script).unwrap();
+        let mut expanded = Position::new(0, 0);
+        navigate(&mut expanded, &expand(&statements, 100).unwrap()).unwrap();
+        let mut direct = Position::new(0, 0);
+        run(&mut direct, &statements, Policy::Error, 100).unwrap();
+        assert_eq!(direct, expanded);
+        assert_eq!(direct, Position::new(1, 0));
+    }
+
+    #[test]
+    fn run_reports_script_line() {
+        let statements = parse_script("down 1\nrepeat 3 {\n  forward 1\n  up 1 # level\n}").unwrap();
+        let diagnostics = run(&mut Submarine::new(0, 0, 0), &statements, Policy::Allow, 100).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].to_string(), "Line 4: 'up 1' takes aim to -1, below zero");
+        assert!(run(&mut Submarine::new(0, 0, 0), &statements, Policy::Error, 100).is_err());
+    }
+
+    #[test]
+    fn expansion_limit() {
+        let statements = parse_script("repeat 1000 { repeat 1000 { forward 1 } }").unwrap();
+        assert!(matches!(expand(&statements, 999_999), Err(AocError::Invalid(_))));
+        assert_eq!(expand(&statements, 1_000_000).unwrap().len(), 1_000_000);
+    }
+
+    #[test]
+    fn run_limit() {
+        let statements = parse_script("repeat 1000 { repeat 1000 { forward 1 } }").unwrap();
+        let mut position = Position::new(0, 0);
+        assert!(matches!(run(&mut position, &statements, Policy::Error, 999), Err(AocError::Invalid(_))));
+        let mut position = Position::new(0, 0);
+        run(&mut position, &statements, Policy::Error, 1_000_000).unwrap();
+        assert_eq!(position, Position::new(0, 1_000_000));
+    }
+
+    fn error(script: &str) -> (usize, usize, String) {
+        match parse_commands(script) {
+            Err(AocError::Parse { line, column, message }) => (line, column, message),
+            other => panic!("Expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn errors_point_at_line() {
+        let (line, column, message) = error("forward 1\nrepeat 2 {\n  up x\n}");
+        assert_eq!((line, column), (3, 6));
+        assert!(message.starts_with("expected number\n3 |   up x"), "{}", message);
+
+        assert_eq!(error("forward 1\nsideways 2").0, 2);
+        assert_eq!(error("repeat 2 {\nforward 1\n").0, 3);
+        assert_eq!(error("forward 1 2").1, 11);
+        assert_eq!(error("forward 1\n}").0, 2);
+    }
+}
@@ -0,0 +1,259 @@
+use std::str::FromStr;
+
+use crate::AocError;
+use crate::day02::model::Step;
+use crate::day02::navigation::{Diagnostic, Navigation, pilot_each, Policy};
+
+/// State of the submarine after a step, or at the start when there is no step.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Waypoint {
+    pub step: Option<Step>,
+    pub horizontal: i64,
+    pub depth: i64,
+    /// Only the aim model has one.
+    pub aim: Option<i64>,
+}
+
+impl Waypoint {
+    fn of<N: Navigation + ?Sized>(step: Option<Step>, model: &N) -> Self {
+        Waypoint { step, horizontal: model.horizontal(), depth: model.depth(), aim: model.aim() }
+    }
+}
+
+/// Path of the submarine, starting with its state before the first step.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Trajectory {
+    pub waypoints: Vec<Waypoint>,
+}
+
+/// Executes the steps like [pilot](crate::day02::navigation::pilot), recording the state after each of them.
+pub fn record<N: Navigation + ?Sized>(model: &mut N, steps: &[Step], policy: Policy) -> Result<(Trajectory, Vec<Diagnostic>), AocError> {
+    let mut waypoints = Vec::with_capacity(steps.len() + 1);
+    waypoints.push(Waypoint::of(None, model));
+    let diagnostics = pilot_each(model, steps, policy, |step, model| waypoints.push(Waypoint::of(Some(*step), model)))?;
+    Ok((Trajectory { waypoints }, diagnostics))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Export {
+    Csv,
+    Json,
+    /// Depth against horizontal position drawn with characters.
+    Ascii,
+    /// Same plot as an SVG image.
+    Svg,
+}
+
+impl FromStr for Export {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Export::Csv),
+            "json" => Ok(Export::Json),
+            "ascii" => Ok(Export::Ascii),
+            "svg" => Ok(Export::Svg),
+            other => Err(format!("Unknown trajectory format: {}", other)),
+        }
+    }
+}
+
+const ASCII_WIDTH: usize = 72;
+const ASCII_HEIGHT: usize = 24;
+const SVG_WIDTH: f64 = 800.0;
+const SVG_HEIGHT: f64 = 400.0;
+const SVG_MARGIN: f64 = 20.0;
+
+impl Trajectory {
+    pub fn render(&self, format: Export) -> Result<String, AocError> {
+        match format {
+            Export::Csv => Ok(self.csv()),
+            Export::Json => Ok(self.json()),
+            Export::Ascii => self.ascii(ASCII_WIDTH, ASCII_HEIGHT),
+            Export::Svg => Ok(self.svg()),
+        }
+    }
+
+    /// Waypoints of the steps after which the aim is different than before them.
+    pub fn aim_changes(&self) -> impl Iterator<Item=&Waypoint> {
+        self.waypoints.windows(2).filter(|pair| pair[0].aim != pair[1].aim).map(|pair| &pair[1])
+    }
+
+    /// One row per waypoint, the start has line 0 and no command. Aim is empty for models without one.
+    pub fn csv(&self) -> String {
+        let mut csv = "line,command,horizontal,depth,aim\n".to_owned();
+        for waypoint in &self.waypoints {
+            csv += &format!("{},{},{},{},{}\n",
+                            waypoint.step.map_or(0, |s| s.line),
+                            waypoint.step.map_or(String::new(), |s| s.command.to_string()),
+                            waypoint.horizontal, waypoint.depth,
+                            waypoint.aim.map_or(String::new(), |aim| aim.to_string()));
+        }
+        csv
+    }
+
+    pub fn json(&self) -> String {
+        let rows: Vec<String> = self.waypoints.iter()
+            .map(|waypoint| format!(r#"  {{"line": {}, "command": {}, "horizontal": {}, "depth": {}, "aim": {}}}"#,
+                                    waypoint.step.map_or(0, |s| s.line),
+                                    waypoint.step.map_or("null".to_owned(), |s| format!("\"{}\"", s.command)),
+                                    waypoint.horizontal, waypoint.depth,
+                                    waypoint.aim.map_or("null".to_owned(), |aim| aim.to_string())))
+            .collect();
+        format!("[\n{}\n]\n", rows.join(",\n"))
+    }
+
+    /// Plot of depth (down) against horizontal position (right) in a grid of the given size.
+    /// The path is drawn with `.`, `v` and `^` mark steps that increased or decreased the aim,
+    /// `S` is the start and `E` the end. The grid needs at least one cell in each direction.
+    pub fn ascii(&self, width: usize, height: usize) -> Result<String, AocError> {
+        if width == 0 || height == 0 {
+            return Err(AocError::Invalid(format!("Plot must be at least 1x1, got {}x{}", width, height)));
+        }
+        let bounds = Bounds::of(&self.waypoints);
+        let cell = |w: &Waypoint| (scale(w.horizontal, bounds.horizontal, width), scale(w.depth, bounds.depth, height));
+        let mut grid = vec![vec![' '; width]; height];
+        for pair in self.waypoints.windows(2) {
+            let ((x0, y0), (x1, y1)) = (cell(&pair[0]), cell(&pair[1]));
+            let length = x0.abs_diff(x1).max(y0.abs_diff(y1)).max(1);
+            for idx in 0..=length {
+                let x = x0 as f64 + (x1 as f64 - x0 as f64) * idx as f64 / length as f64;
+                let y = y0 as f64 + (y1 as f64 - y0 as f64) * idx as f64 / length as f64;
+                grid[y.round() as usize][x.round() as usize] = '.';
+            }
+        }
+        for pair in self.waypoints.windows(2) {
+            if let (Some(before), Some(after)) = (pair[0].aim, pair[1].aim) {
+                if before != after {
+                    let (x, y) = cell(&pair[1]);
+                    grid[y][x] = if after > before { 'v' } else { '^' };
+                }
+            }
+        }
+        if let (Some(first), Some(last)) = (self.waypoints.first(), self.waypoints.last()) {
+            let (x, y) = cell(last);
+            grid[y][x] = 'E';
+            let (x, y) = cell(first);
+            grid[y][x] = 'S';
+        }
+
+        let mut plot = format!("depth {}..{} (down), horizontal {}..{} (right)\n",
+                               bounds.depth.0, bounds.depth.1, bounds.horizontal.0, bounds.horizontal.1);
+        plot += &format!("+{}+\n", "-".repeat(width));
+        for row in grid {
+            plot += &format!("|{}|\n", row.into_iter().collect::<String>());
+        }
+        plot += &format!("+{}+\n", "-".repeat(width));
+        Ok(plot)
+    }
+
+    /// Same plot as [Trajectory::ascii], the path as a polyline with circles at steps that changed the aim.
+    pub fn svg(&self) -> String {
+        let bounds = Bounds::of(&self.waypoints);
+        let point = |w: &Waypoint| (
+            SVG_MARGIN + position(w.horizontal, bounds.horizontal) * (SVG_WIDTH - 2.0 * SVG_MARGIN),
+            SVG_MARGIN + position(w.depth, bounds.depth) * (SVG_HEIGHT - 2.0 * SVG_MARGIN),
+        );
+        let path: Vec<String> = self.waypoints.iter()
+            .map(|w| {
+                let (x, y) = point(w);
+                format!("{:.1},{:.1}", x, y)
+            })
+            .collect();
+        let mut svg = format!(r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">
+  <title>depth {} to {}, horizontal {} to {}</title>
+  <rect width="{w}" height="{h}" fill="white"/>
+  <polyline fill="none" stroke="steelblue" stroke-width="1.5" points="{}"/>
+"#, bounds.depth.0, bounds.depth.1, bounds.horizontal.0, bounds.horizontal.1, path.join(" "),
+                              w = SVG_WIDTH, h = SVG_HEIGHT);
+        for waypoint in self.aim_changes() {
+            let (x, y) = point(waypoint);
+            let title = waypoint.step.map_or(String::new(), |s| format!("line {}: {}", s.line, s.command));
+            svg += &format!("  <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"3\" fill=\"orange\"><title>{}</title></circle>\n", x, y, title);
+        }
+        svg += "</svg>\n";
+        svg
+    }
+}
+
+/// Ranges of horizontal position and depth, both including zero.
+struct Bounds {
+    horizontal: (i64, i64),
+    depth: (i64, i64),
+}
+
+impl Bounds {
+    fn of(waypoints: &[Waypoint]) -> Self {
+        let range = |value: fn(&Waypoint) -> i64| waypoints.iter()
+            .fold((0, 0), |(lo, hi), w| (lo.min(value(w)), hi.max(value(w))));
+        Bounds { horizontal: range(|w| w.horizontal), depth: range(|w| w.depth) }
+    }
+}
+
+/// Relative position of the value in the range, from 0 to 1.
+fn position(value: i64, (lo, hi): (i64, i64)) -> f64 {
+    if hi == lo { 0.0 } else { (value as f64 - lo as f64) / (hi as f64 - lo as f64) }
+}
+
+/// Index of the value in the range split into `cells` cells.
+fn scale(value: i64, range: (i64, i64), cells: usize) -> usize {
+    (position(value, range) * (cells - 1) as f64).round() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AocError;
+    use crate::day02::model::Command::{Down, Forward, Up};
+    use crate::day02::model::Step;
+    use crate::day02::navigation::{Policy, Position, Submarine};
+    use crate::day02::trajectory::{record, Trajectory};
+
+    fn sample(policy: Policy) -> Trajectory {
+        let steps = Step::numbered(&[Forward(5), Down(5), Forward(8), Up(3), Down(8), Forward(2)]);
+        record(&mut Submarine::new(0, 0, 0), &steps, policy).unwrap().0
+    }
+
+    #[test]
+    fn records_every_step() {
+        let trajectory = sample(Policy::Error);
+        assert_eq!(trajectory.waypoints.len(), 7);
+        let last = trajectory.waypoints[6];
+        assert_eq!((last.step.unwrap().line, last.horizontal, last.depth, last.aim), (6, 15, 60, Some(10)));
+        let changes: Vec<usize> = trajectory.aim_changes().map(|w| w.step.unwrap().line).collect();
+        assert_eq!(changes, vec![2, 4, 5]);
+    }
+
+    #[test]
+    fn exports() {
+        let trajectory = sample(Policy::Error);
+        let csv = trajectory.csv();
+        assert!(csv.starts_with("line,command,horizontal,depth,aim\n0,,0,0,0\n1,forward 5,5,0,0\n"), "{}", csv);
+        let json = trajectory.json();
+        assert!(json.contains(r#"{"line": 3, "command": "forward 8", "horizontal": 13, "depth": 40, "aim": 5}"#), "{}", json);
+        let svg = trajectory.svg();
+        assert_eq!(svg.matches("<circle").count(), 3);
+
+        let (position, _) = record(&mut Position::new(0, 0), &Step::numbered(&[Forward(1)]), Policy::Error).unwrap();
+        assert!(position.csv().ends_with("1,forward 1,1,0,\n"));
+        assert!(position.json().contains(r#""command": null, "horizontal": 0, "depth": 0, "aim": null"#));
+    }
+
+    #[test]
+    fn ascii_plot() {
+        let plot = sample(Policy::Error).ascii(16, 7).unwrap();
+        let rows: Vec<&str> = plot.lines().collect();
+        assert_eq!(rows[0], "depth 0..60 (down), horizontal 0..15 (right)");
+        assert_eq!(rows.len(), 7 + 3);
+        assert_eq!(rows[2], "|S....v          |");
+        assert_eq!(rows[6], "|            .v  |");
+        assert_eq!(rows[8], "|               E|");
+    }
+
+    #[test]
+    fn ascii_plot_needs_a_cell() {
+        let trajectory = sample(Policy::Error);
+        assert!(matches!(trajectory.ascii(0, 7), Err(AocError::Invalid(_))));
+        assert!(matches!(trajectory.ascii(16, 0), Err(AocError::Invalid(_))));
+        assert_eq!(trajectory.ascii(1, 1).unwrap().lines().nth(2), Some("|S|"));
+    }
+}
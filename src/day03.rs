@@ -0,0 +1,309 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::{as_vec, AocError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<BinaryNumber>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(binaries: &Self::Input) -> Result<Self::Answer1, AocError> {
+        part1(binaries)
+    }
+
+    fn part2(binaries: &Self::Input) -> Result<Self::Answer2, AocError> {
+        part2(binaries)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<BinaryNumber>, AocError> {
+    as_vec(input)
+}
+
+pub fn part1(binaries: &[BinaryNumber]) -> Result<u128, AocError> {
+    let frequencies = get_frequencies(binaries)?;
+    let gamma = frequencies.gamma();
+    let epsilon = frequencies.epsilon();
+
+    Ok(product(&gamma, &epsilon))
+}
+
+pub fn part2(binaries: &[BinaryNumber]) -> Result<u128, AocError> {
+    let scrubber_rating_bin = scrubber_rating(0, binaries)?;
+    let oxygen_rating_bin = oxygen_rating(0, binaries)?;
+
+    Ok(product(&scrubber_rating_bin, &oxygen_rating_bin))
+}
+
+/// Both answers multiply two ratings of up to 64 bits, so the product always fits into 128 bits.
+fn product(first: &BinaryNumber, second: &BinaryNumber) -> u128 {
+    first.decimal() as u128 * second.decimal() as u128
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BinaryNumber {
+    str: String,
+}
+
+impl BinaryNumber {
+    pub fn as_str(&self) -> &str {
+        &self.str
+    }
+
+    /// Width is checked when parsing, so the conversion can't overflow.
+    pub fn decimal(&self) -> u64 {
+        u64::from_str_radix(&self.str, 2).unwrap_or_else(|_| panic!("BinaryNumber {:?} is not binary?!", self))
+    }
+
+    pub fn bit_match(&self, bit_idx: usize, bit: char) -> bool {
+        if let Some(c) = self.str.chars().nth(bit_idx) {
+            c == bit
+        } else {
+            false
+        }
+    }
+}
+
+impl Display for BinaryNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.str)
+    }
+}
+
+impl FromStr for BinaryNumber {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid_str = s.chars().all(|c| c == '1' || c == '0');
+        if !valid_str {
+            Err(format!("Invalid binary string: {}", s))
+        } else if s.is_empty() || s.len() > 64 {
+            Err(format!("Binary string must have 1 to 64 bits: {}", s))
+        } else {
+            Ok(BinaryNumber { str: s.to_owned() })
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct BitFrequencies {
+    frequencies: Vec<i32>,
+}
+
+impl BitFrequencies {
+    pub fn frequencies(&self) -> &[i32] {
+        &self.frequencies
+    }
+
+    pub fn gamma(&self) -> BinaryNumber {
+        let str = self.frequencies.iter()
+            .map(|fq| if *fq > 0 { '1' } else { '0' })
+            .collect();
+        BinaryNumber { str }
+    }
+
+    pub fn epsilon(&self) -> BinaryNumber {
+        let str = self.frequencies.iter()
+            .map(|fq| if *fq > 0 { '0' } else { '1' })
+            .collect();
+        BinaryNumber { str }
+    }
+
+    pub fn oxygen_bit(&self, bit_idx: usize) -> Option<char> {
+        if let Some(bit) = self.frequencies.get(bit_idx) {
+            let bit_char = if *bit >= 0 { '1' } else { '0' };
+            Some(bit_char)
+        } else {
+            None
+        }
+    }
+
+    pub fn scrubber_bit(&self, bit_idx: usize) -> Option<char> {
+        if let Some(bit) = self.frequencies.get(bit_idx) {
+            let bit_char = if *bit >= 0 { '0' } else { '1' };
+            Some(bit_char)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<&BinaryNumber> for BitFrequencies {
+    fn from(binary: &BinaryNumber) -> Self {
+        let frequencies = binary.str.chars()
+            .map(|bit| if bit == '1' { 1 } else { -1 })
+            .collect();
+        BitFrequencies { frequencies }
+    }
+}
+
+
+pub fn merge(first: BitFrequencies, second: BitFrequencies) -> Result<BitFrequencies, AocError> {
+    if first.frequencies.len() != second.frequencies.len() {
+        Err(AocError::Invalid(format!("Binary numbers have different widths: {} and {}",
+                                      first.frequencies.len(), second.frequencies.len())))
+    } else {
+        let frequencies = first.frequencies.iter().zip(second.frequencies.iter())
+            .map(|(first, second)| first + second)
+            .collect();
+        Ok(BitFrequencies { frequencies })
+    }
+}
+
+pub fn get_frequencies(binaries: &[BinaryNumber]) -> Result<BitFrequencies, AocError> {
+    binaries.iter()
+        .map(BitFrequencies::from)
+        .try_fold(None, |acc: Option<BitFrequencies>, current| match acc {
+            None => Ok(Some(current)),
+            Some(acc) => merge(acc, current).map(Some),
+        })?
+        .ok_or_else(|| AocError::NoSolution("Input can't be empty".to_owned()))
+}
+
+pub fn oxygen_rating(current_bit: usize, binaries: &[BinaryNumber]) -> Result<BinaryNumber, AocError> {
+    if binaries.len() == 1 {
+        return Ok(binaries[0].clone());
+    }
+    let frequencies = get_frequencies(binaries)?;
+    if let Some(oxygen_bit) = frequencies.oxygen_bit(current_bit) {
+        let matching_binaries: Vec<BinaryNumber> = binaries.iter()
+            .filter(|b| b.bit_match(current_bit, oxygen_bit))
+            .cloned()
+            .collect();
+        oxygen_rating(current_bit + 1, &matching_binaries)
+    } else {
+        Err(AocError::NoSolution(format!("Unable to find oxygen rating. Remaining binaries: {:?}", binaries)))
+    }
+}
+
+pub fn scrubber_rating(current_bit: usize, binaries: &[BinaryNumber]) -> Result<BinaryNumber, AocError> {
+    if binaries.len() == 1 {
+        return Ok(binaries[0].clone());
+    }
+    let frequencies = get_frequencies(binaries)?;
+    if let Some(scrubber_bit) = frequencies.scrubber_bit(current_bit) {
+        let matching_binaries: Vec<BinaryNumber> = binaries.iter()
+            .filter(|b| b.bit_match(current_bit, scrubber_bit))
+            .cloned()
+            .collect();
+        scrubber_rating(current_bit + 1, &matching_binaries)
+    } else {
+        Err(AocError::NoSolution(format!("Unable to find scrubber rating. Remaining binaries: {:?}", binaries)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day03::{BinaryNumber, Day03, get_frequencies, oxygen_rating, scrubber_rating};
+    use crate::{AocError, Solution};
+
+    const SAMPLE_INPUT: &str = r#"
+        00100
+        11110
+        10110
+        10111
+        10101
+        01111
+        00111
+        11100
+        10000
+        11001
+        00010
+        01010
+        "#;
+
+    #[test]
+    fn solve_sample_part1() -> Result<(), AocError> {
+        let binaries = Day03::parse(SAMPLE_INPUT)?;
+        assert_eq!(198, Day03::part1(&binaries)?);
+        Ok(())
+    }
+
+    #[test]
+    fn solve_sample_part2() -> Result<(), AocError> {
+        let binaries = Day03::parse(SAMPLE_INPUT)?;
+        assert_eq!(230, Day03::part2(&binaries)?);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_binary() {
+        let input = "00100";
+        let expected = BinaryNumber { str: input.to_owned() };
+        let result = input.parse();
+        assert_eq!(Ok(expected), result)
+    }
+
+    #[test]
+    fn to_decimal() {
+        let binary = BinaryNumber { str: "00100".to_owned() };
+        assert_eq!(4, binary.decimal())
+    }
+
+    #[test]
+    fn epsilon() {
+        let binaries: Vec<BinaryNumber> = crate::as_vec(SAMPLE_INPUT).unwrap();
+        let frequencies = get_frequencies(&binaries).unwrap();
+        let expected = BinaryNumber { str: "01001".to_owned() };
+        assert_eq!(expected, frequencies.epsilon())
+    }
+
+    #[test]
+    fn gamma() {
+        let binaries: Vec<BinaryNumber> = crate::as_vec(SAMPLE_INPUT).unwrap();
+        let frequencies = get_frequencies(&binaries).unwrap();
+        let expected = BinaryNumber { str: "10110".to_owned() };
+        assert_eq!(expected, frequencies.gamma())
+    }
+
+    #[test]
+    fn test_oxygen_rating() {
+        let binaries: Vec<BinaryNumber> = crate::as_vec(SAMPLE_INPUT).unwrap();
+        let expected = BinaryNumber { str: "10111".to_owned() };
+        let result = oxygen_rating(0, &binaries).unwrap();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn test_scrubber_rating() {
+        let binaries: Vec<BinaryNumber> = crate::as_vec(SAMPLE_INPUT).unwrap();
+        let expected = BinaryNumber { str: "01010".to_owned() };
+        let result = scrubber_rating(0, &binaries).unwrap();
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn scrubber_rating_with_shared_bit() {
+        let binaries: Vec<BinaryNumber> = crate::as_vec("0110\n0111").unwrap();
+        assert!(matches!(scrubber_rating(0, &binaries), Err(AocError::NoSolution(_))));
+    }
+
+    #[test]
+    fn different_widths() {
+        let binaries: Vec<BinaryNumber> = crate::as_vec("101\n1010").unwrap();
+        assert!(matches!(get_frequencies(&binaries), Err(AocError::Invalid(_))));
+    }
+
+    #[test]
+    fn wide_binaries() -> Result<(), AocError> {
+        let binaries = Day03::parse("11110000111100001111\n11110000111100001110\n00001111000011110000")?;
+        assert_eq!(0b11110000111100001110 * 0b00001111000011110001, Day03::part1(&binaries)?);
+        assert_eq!(0b11110000111100001111 * 0b00001111000011110000, Day03::part2(&binaries)?);
+        let widest = Day03::parse(&format!("{}\n0{}", "1".repeat(64), "1".repeat(63)))?;
+        assert_eq!(u64::MAX as u128 * (u64::MAX >> 1) as u128, Day03::part2(&widest)?);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(matches!(Day03::part1(&vec![]), Err(AocError::NoSolution(_))));
+    }
+}
@@ -1,12 +1,13 @@
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
 use std::ops::{Div, Rem};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct BoardNumber {
     pub value: u8,
     pub checked: bool,
-    row: u8,
-    col: u8,
+    pub row: u8,
+    pub col: u8,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -75,11 +76,25 @@ impl Board {
                 let checked = if number.checked { "*" } else { "" };
                 print!("{}{} ", checked, number.value)
             }
-            print!("\n")
+            println!()
         }
     }
 }
 
+/// Prints the board the way it appears in the puzzle input, five right-aligned numbers per line.
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut numbers: Vec<&BoardNumber> = self.numbers.values().collect();
+        // Numbers are indexed by column first, see idx_to_position.
+        numbers.sort_by_key(|n| (n.col, n.row));
+        for line in numbers.chunks(5) {
+            let line: Vec<String> = line.iter().map(|n| format!("{:>2}", n.value)).collect();
+            writeln!(f, "{}", line.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
 fn create_board_number(index: usize, value: u8) -> BoardNumber {
     let (row, col) = idx_to_position(index);
     BoardNumber {
@@ -99,9 +114,10 @@ fn idx_to_position(index: usize) -> (u8, u8) {
 
 #[cfg(test)]
 mod tests {
-    use crate::board::Board;
+    use crate::day04::board::Board;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_bingo() {
         let mut board = Board::new((0..25).collect());
         assert_eq!(board.bingo(), false);
@@ -110,4 +126,11 @@ mod tests {
         }
         assert_eq!(board.bingo(), true)
     }
+
+    #[test]
+    fn display() {
+        let board = Board::new((0..25).collect());
+        let expected = " 0  1  2  3  4\n 5  6  7  8  9\n10 11 12 13 14\n15 16 17 18 19\n20 21 22 23 24\n";
+        assert_eq!(board.to_string(), expected);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,41 @@
+use crate::{AocError, Solution};
+use crate::day04::board::Board;
+
+pub mod board;
+pub mod parser;
+pub mod part1;
+pub mod part2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = (Vec<u8>, Vec<Board>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1((numbers, boards): &Self::Input) -> Result<Self::Answer1, AocError> {
+        part1(numbers, boards)
+    }
+
+    fn part2((numbers, boards): &Self::Input) -> Result<Self::Answer2, AocError> {
+        part2(numbers, boards)
+    }
+}
+
+/// Parses drawn numbers and bingo boards.
+pub fn parse(input: &str) -> Result<(Vec<u8>, Vec<Board>), AocError> {
+    parser::parse(input)
+}
+
+pub fn part1(numbers: &[u8], boards: &[Board]) -> Result<u32, AocError> {
+    part1::solve(numbers, boards.to_vec())
+}
+
+pub fn part2(numbers: &[u8], boards: &[Board]) -> Result<u32, AocError> {
+    part2::solve(numbers.to_vec(), boards.to_vec())
+}
@@ -1,49 +1,37 @@
-extern crate nom;
+use nom::character::complete::u8 as p_u8;
+use nom::combinator::map;
+use nom::error::context;
+use nom::sequence::pair;
 
-use nom::bytes::complete::tag;
-use nom::character::complete::{multispace0, u8 as p_u8};
-use nom::combinator::{eof, map};
-use nom::IResult;
-use nom::multi::{many0, many_m_n, separated_list1};
+use crate::AocError;
+use crate::day04::board::Board;
+use crate::parse::{blocks, comma_list, grid, parse_all, ParseResult};
 
-use crate::board::Board;
-
-fn selected_numbers(s: &str) -> IResult<&str, Vec<u8>> {
-    separated_list1(tag(","), p_u8)(s)
+pub fn selected_numbers(s: &str) -> ParseResult<'_, Vec<u8>> {
+    comma_list(number)(s)
 }
 
-fn board(s: &str) -> IResult<&str, Board> {
-    let numbers_p = many_m_n(25, 25, number);
-    map(numbers_p, |numbers| Board::new(numbers))(s)
+pub fn board(s: &str) -> ParseResult<'_, Board> {
+    map(grid(5, 5, number), Board::new)(s)
 }
 
-fn number(s: &str) -> IResult<&str, u8> {
-    let (s, _) = multispace0(s)?;
-    p_u8(s)
+fn number(s: &str) -> ParseResult<'_, u8> {
+    context("number from 0 to 255", p_u8)(s)
 }
 
 
-fn puzzle_input(s: &str) -> IResult<&str, (Vec<u8>, Vec<Board>)> {
-    let (s, _) = multispace0(s)?;
-    let (s, numbers) = selected_numbers(s)?;
-    let (s, boards) = many0(board)(s)?;
-    let (s, _) = multispace0(s)?;
-    let (s, _) = eof(s)?;
-    Ok((s, (numbers, boards)))
+fn puzzle_input(s: &str) -> ParseResult<'_, (Vec<u8>, Vec<Board>)> {
+    pair(selected_numbers, blocks(board))(s)
 }
 
-pub fn parse(s: &str) -> Result<(Vec<u8>, Vec<Board>), String> {
-    match puzzle_input(s) {
-        Ok(("", (numbers, boards))) => Ok((numbers, boards)),
-        Err(_e) => Err("Parsing failed".to_owned()),
-        Ok((leftover, _)) => Err(format!("Leftover input: {}", leftover))
-    }
+pub fn parse(s: &str) -> Result<(Vec<u8>, Vec<Board>), AocError> {
+    parse_all(s, puzzle_input)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::board::Board;
-    use crate::parser::{board, puzzle_input, selected_numbers};
+    use crate::day04::board::Board;
+    use crate::day04::parser::{board, puzzle_input, selected_numbers};
 
     #[test]
     fn test_selected_numbers() {
@@ -1,10 +1,11 @@
-use crate::board::Board;
+use crate::AocError;
+use crate::day04::board::Board;
 
-pub fn solve(numbers: &[u8], boards: Vec<Board>) -> u32 {
+pub fn solve(numbers: &[u8], boards: Vec<Board>) -> Result<u32, AocError> {
     if let Some((number, board)) = find_board(numbers, boards) {
-        sum_unchecked(&board) * (number as u32)
+        Ok(sum_unchecked(&board) * (number as u32))
     } else {
-        0
+        Err(AocError::NoSolution("No board wins".to_owned()))
     }
 }
 
@@ -29,11 +30,13 @@ fn find_board(numbers: &[u8], mut boards: Vec<Board>) -> Option<(u8, Board)> {
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::parse;
-    use crate::part1::{find_board, solve};
+    use crate::AocError;
+    use crate::day04::parser::parse;
+    #[allow(unused_imports)]
+    use crate::day04::part1::{find_board, solve};
 
     #[test]
-    fn solve_sample() -> Result<(), String> {
+    fn solve_sample() -> Result<(), AocError> {
         let sample = r#"
 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
@@ -55,7 +58,7 @@ mod tests {
 22 11 13  6  5
  2  0 12  3  7"#;
         let (numbers, boards) = parse(sample)?;
-        assert_eq!(solve(&numbers, boards), 4512);
+        assert_eq!(solve(&numbers, boards)?, 4512);
         Ok(())
     }
 }
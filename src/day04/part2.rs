@@ -1,11 +1,12 @@
 use std::collections::VecDeque;
-use crate::board::Board;
+use crate::AocError;
+use crate::day04::board::Board;
 
-pub fn solve(numbers: Vec<u8>, boards: Vec<Board>) -> u32 {
+pub fn solve(numbers: Vec<u8>, boards: Vec<Board>) -> Result<u32, AocError> {
     if let Some(bingo_board) = find_board(numbers, boards) {
-        sum_unchecked(&bingo_board.board) * (bingo_board.last_number as u32)
+        Ok(sum_unchecked(&bingo_board.board) * (bingo_board.last_number as u32))
     } else {
-        0
+        Err(AocError::NoSolution("No board wins".to_owned()))
     }
 }
 
@@ -57,11 +58,12 @@ struct BingoBoard {
 
 #[cfg(test)]
 mod d04_tests {
-    use crate::parser::parse;
-    use crate::part2::{solve};
+    use crate::AocError;
+    use crate::day04::parser::parse;
+    use crate::day04::part2::{solve};
 
     #[test]
-    fn solve_sample() -> Result<(), String> {
+    fn solve_sample() -> Result<(), AocError> {
         let sample = r#"
 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
@@ -83,7 +85,7 @@ mod d04_tests {
 22 11 13  6  5
  2  0 12  3  7"#;
         let (numbers, boards) = parse(sample)?;
-        assert_eq!(solve(numbers, boards), 1924);
+        assert_eq!(solve(numbers, boards)?, 1924);
         Ok(())
     }
 }
@@ -0,0 +1,42 @@
+use crate::{AocError, Solution};
+use crate::day05::model::Line;
+
+pub mod model;
+pub mod parser;
+pub mod part1;
+pub mod part2;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Vec<Line>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Answer1, AocError> {
+        Ok(part1(lines))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Answer2, AocError> {
+        Ok(part2(lines))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Line>, AocError> {
+    parser::parse(input)
+}
+
+/// Counts points covered by at least two horizontal or vertical lines.
+pub fn part1(lines: &[Line]) -> u32 {
+    part1::solve(lines, part1::line_filter)
+}
+
+/// Counts points covered by at least two lines of any kind.
+pub fn part2(lines: &[Line]) -> u32 {
+    part1::solve(lines, part2::line_filter)
+}
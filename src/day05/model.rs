@@ -1,16 +1,17 @@
 use std::cmp::{max, min};
+use std::fmt::{Display, Formatter};
 use std::iter::Rev;
 use std::ops::RangeInclusive;
 
-use crate::model::PointsRange::{Forward, Reversed};
+use crate::day05::model::PointsRange::{Forward, Reversed};
 
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Line {
     pub from: Point,
     pub to: Point,
 }
 
 impl Line {
-    #[allow(dead_code)]
     pub fn new(from: Point, to: Point) -> Self {
         Line { from, to }
     }
@@ -33,14 +34,14 @@ impl Line {
             Horizontal => {
                 let min_x = min(self.from.x, self.to.x);
                 let max_x = max(self.from.x, self.to.x);
-                (min_x..=max_x).into_iter()
+                (min_x..=max_x)
                     .map(|x| Point::new(x, self.from.y))
                     .collect()
             }
             Vertical => {
                 let min_y = min(self.from.y, self.to.y);
                 let max_y = max(self.from.y, self.to.y);
-                (min_y..=max_y).into_iter()
+                (min_y..=max_y)
                     .map(|y| Point::new(self.from.x, y))
                     .collect()
             }
@@ -63,6 +64,12 @@ impl Line {
     }
 }
 
+impl Display for Line {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.from, self.to)
+    }
+}
+
 enum PointsRange {
     Forward(RangeInclusive<u16>),
     Reversed(Rev<RangeInclusive<u16>>),
@@ -80,7 +87,7 @@ impl Iterator for PointsRange {
 }
 
 
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 pub struct Point {
     pub x: u16,
     pub y: u16,
@@ -92,6 +99,12 @@ impl Point {
     }
 }
 
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum LineType {
     Horizontal,
@@ -101,7 +114,7 @@ pub enum LineType {
 
 #[cfg(test)]
 mod tests {
-    use crate::model::{Line, LineType, Point};
+    use crate::day05::model::{Line, LineType, Point};
 
     #[test]
     fn line_types() {
@@ -0,0 +1,50 @@
+use nom::character::complete::{char, u16 as p_u16};
+use nom::combinator::map;
+use nom::error::context;
+use nom::sequence::separated_pair;
+
+use crate::AocError;
+use crate::day05::model::{Line, Point};
+use crate::parse::{arrow_pair, lines, parse_all, ParseResult};
+
+pub fn point(s: &str) -> ParseResult<'_, Point> {
+    let coordinate = |s| context("coordinate", p_u16)(s);
+    map(separated_pair(coordinate, char(','), coordinate), |(x, y)| Point { x, y })(s)
+}
+
+pub fn line(s: &str) -> ParseResult<'_, Line> {
+    map(arrow_pair(point, point), |(from, to)| Line { from, to })(s)
+}
+
+fn puzzle_input(s: &str) -> ParseResult<'_, Vec<Line>> {
+    lines(line)(s)
+}
+
+pub fn parse(s: &str) -> Result<Vec<Line>, AocError> {
+    parse_all(s, puzzle_input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day05::parser::puzzle_input;
+
+    #[test]
+    fn test_puzzle_input() -> Result<(), nom::Err<nom::error::VerboseError<&'static str>>> {
+        let input = r#"
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+"#;
+        let (s, lines) = puzzle_input(input)?;
+        assert_eq!(s, "");
+        assert_eq!(lines.len(), 10);
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::model::{Line, LineType, Point};
+use crate::day05::model::{Line, LineType, Point};
 
 
 pub fn line_filter(line: &Line) -> bool {
@@ -32,13 +32,13 @@ pub fn solve(lines: &[Line], filter: impl Fn(&Line) -> bool) -> u32 {
 
 #[cfg(test)]
 mod tests {
-    use aoc2021::BoxError;
+    use crate::AocError;
 
-    use crate::parser;
-    use crate::part1::{line_filter, solve};
+    use crate::day05::parser;
+    use crate::day05::part1::{line_filter, solve};
 
     #[test]
-    fn solve_sample() -> Result<(), BoxError> {
+    fn solve_sample() -> Result<(), AocError> {
         let input = r#"
 0,9 -> 5,9
 8,0 -> 0,8
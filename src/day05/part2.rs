@@ -1,4 +1,4 @@
-use crate::model::Line;
+use crate::day05::model::Line;
 
 pub fn line_filter(_line: &Line) -> bool {
     true
@@ -6,14 +6,14 @@ pub fn line_filter(_line: &Line) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use aoc2021::BoxError;
-    use crate::parser;
-    use crate::part1;
+    use crate::AocError;
+    use crate::day05::parser;
+    use crate::day05::part1;
 
     use super::*;
 
     #[test]
-    fn test_sample() -> Result<(), BoxError> {
+    fn test_sample() -> Result<(), AocError> {
         let input = r#"
 0,9 -> 5,9
 8,0 -> 0,8
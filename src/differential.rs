@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::generate::{generate, Options};
+use crate::reference::{self, Reference};
+use crate::{AocError, Day, Part};
+
+/// Input on which the real solution and the reference give different answers.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub day: u8,
+    pub seed: u64,
+    /// Smallest input found that still shows the difference.
+    pub input: String,
+    pub expected: Result<Vec<String>, String>,
+    pub actual: Result<Vec<String>, String>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let answers = |result: &Result<Vec<String>, String>| match result {
+            Ok(answers) => answers.join(", "),
+            Err(err) => format!("error: {}", err),
+        };
+        writeln!(f, "Day {:02} disagrees with the reference on input generated from seed {}", self.day, self.seed)?;
+        writeln!(f, "Reference: {}", answers(&self.expected))?;
+        writeln!(f, "Solution:  {}", answers(&self.actual))?;
+        write!(f, "Shrunk input ({} lines):\n{}", self.input.lines().count(), self.input)
+    }
+}
+
+/// Runs the day and its reference on inputs generated from each seed and returns the first disagreement.
+/// Panics of the solution are caught and reported as errors. The panic hook is left alone, so each of them
+/// still prints the usual message unless the caller installs a quiet hook.
+pub fn check(day: &Day, seeds: Range<u64>, size: usize) -> Result<Option<Disagreement>, AocError> {
+    let reference = reference::for_day(day.day)
+        .ok_or_else(|| AocError::Invalid(format!("Day {} has no reference solution", day.day)))?;
+    first_disagreement(day, reference, seeds, size)
+}
+
+fn first_disagreement(day: &Day, reference: Reference, seeds: Range<u64>, size: usize)
+                      -> Result<Option<Disagreement>, AocError> {
+    for seed in seeds {
+        let input = generate(day.day, &Options { seed, size: Some(size), width: None })?;
+        if disagree(day, reference, &input) {
+            let input = shrink(&input, |candidate| disagree(day, reference, candidate));
+            let expected = reference(&input);
+            let actual = solve(day, &input);
+            return Ok(Some(Disagreement { day: day.day, seed, input, expected, actual }));
+        }
+    }
+    Ok(None)
+}
+
+/// Both failing counts as agreement, the error messages of the two implementations differ.
+fn disagree(day: &Day, reference: Reference, input: &str) -> bool {
+    match (reference(input), solve(day, input)) {
+        (Ok(expected), Ok(actual)) => expected != actual,
+        (Err(_), Err(_)) => false,
+        _ => true,
+    }
+}
+
+/// A panic is reported like any other error, so that overflows show up as disagreements.
+fn solve(day: &Day, input: &str) -> Result<Vec<String>, String> {
+    match catch_unwind(AssertUnwindSafe(|| day.solve(input, &Part::BOTH))) {
+        Ok(result) => result.map_err(|err| err.to_string()),
+        Err(_) => Err("solution panicked".to_owned()),
+    }
+}
+
+/// Removes chunks of the input for as long as `failing` still holds, starting with large chunks.
+/// Chunks are blank line separated blocks if the input has them, lines otherwise. The first block,
+/// e.g. the bingo draws, is always kept. Input without anything to remove comes back unchanged.
+pub fn shrink(input: &str, failing: impl Fn(&str) -> bool) -> String {
+    let blocks = input.contains("\n\n");
+    let separator = if blocks { "\n\n" } else { "\n" };
+    let mut chunks: Vec<&str> = input.split(separator).map(|c| c.trim_end()).filter(|c| !c.is_empty()).collect();
+    let fixed = if blocks { 1 } else { 0 };
+    let join = |chunks: &[&str]| chunks.join(separator) + "\n";
+    if chunks.len() <= fixed {
+        return input.to_owned();
+    }
+
+    let mut size = (chunks.len() - fixed).div_ceil(2);
+    while size > 0 {
+        let mut start = fixed;
+        let mut removed = false;
+        while start < chunks.len() {
+            let end = (start + size).min(chunks.len());
+            let candidate: Vec<&str> = chunks[..start].iter().chain(&chunks[end..]).copied().collect();
+            if failing(&join(&candidate)) {
+                chunks = candidate;
+                removed = true;
+            } else {
+                start += size;
+            }
+        }
+        if !removed {
+            size /= 2;
+        }
+    }
+    join(&chunks)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::differential::shrink;
+
+    #[test]
+    fn shrink_lines() {
+        let input: String = (1..=100).map(|n| format!("{}\n", n)).collect();
+        let failing = |s: &str| s.lines().any(|l| l == "37") && s.lines().any(|l| l == "64");
+        assert_eq!(shrink(&input, failing), "37\n64\n");
+    }
+
+    #[test]
+    fn shrink_keeps_first_block() {
+        let input = "header\n\na\nb\n\nc\n\nd\n";
+        assert_eq!(shrink(input, |s| s.contains('c')), "header\n\nc\n");
+    }
+
+    #[test]
+    fn shrink_nothing_to_remove() {
+        assert_eq!(shrink("\n\n\n", |_| true), "\n\n\n");
+        assert_eq!(shrink("header\n\n", |_| true), "header\n\n");
+        assert_eq!(shrink("", |_| true), "");
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+
+use crate::AocError;
+
+/// Small deterministic pseudo-random generator (SplitMix64), so that a seed always gives the same input.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound`. The bound must not be zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// Uniform value in `low..=high`.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.below(high - low + 1)
+    }
+
+    /// True with probability `percent` / 100.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// What to generate. `size` counts the main items of the day (depths, commands, numbers, boards or lines),
+/// `width` is the number of bits of day 3 binaries.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Options {
+    pub seed: u64,
+    pub size: Option<usize>,
+    pub width: Option<usize>,
+}
+
+/// Whether [generate] knows the input format of the day, found out by generating a tiny input.
+pub fn supports(day: u8) -> bool {
+    generate(day, &Options { seed: 0, size: Some(1), width: None }).is_ok()
+}
+
+/// Generates a valid random input of the day, similar to the real ones unless the size says otherwise.
+pub fn generate(day: u8, options: &Options) -> Result<String, AocError> {
+    let mut rng = Rng::new(options.seed);
+    let size = |default| options.size.unwrap_or(default);
+    if options.width.is_some() && day != 3 {
+        return Err(AocError::Invalid("Width can only be set for day 3".to_owned()));
+    }
+    match day {
+        1 => Ok(depths(&mut rng, size(2000))),
+        2 => Ok(commands(&mut rng, size(1000))),
+        3 => binaries(&mut rng, size(1000), options.width.unwrap_or(12)),
+        4 => Ok(bingo(&mut rng, size(100))),
+        5 => Ok(vents(&mut rng, size(500))),
+        other => Err(AocError::Invalid(format!("No generator for day {}", other))),
+    }
+}
+
+/// Random walk of depths that mostly goes down, like a sonar sweep.
+pub fn depths(rng: &mut Rng, count: usize) -> String {
+    let mut depth = rng.range(100, 200) as i64;
+    let mut out = String::new();
+    for _ in 0..count {
+        out += &format!("{}\n", depth);
+        depth = (depth + rng.range(0, 40) as i64 - 15).max(0);
+    }
+    out
+}
+
+/// Commands with values 1 to 9. `up` never takes the aim below zero.
+pub fn commands(rng: &mut Rng, count: usize) -> String {
+    let mut aim = 0;
+    let mut out = String::new();
+    for _ in 0..count {
+        let value = rng.range(1, 9);
+        let command = match rng.below(3) {
+            0 => "forward",
+            1 if aim >= value => {
+                aim -= value;
+                "up"
+            }
+            _ => {
+                aim += value;
+                "down"
+            }
+        };
+        out += &format!("{} {}\n", command, value);
+    }
+    out
+}
+
+/// Binary numbers of the same width. They are distinct whenever the width allows it, and filtering them
+/// by the most or the least common bit ends with a single number, so that both day 3 ratings exist.
+pub fn binaries(rng: &mut Rng, count: usize, width: usize) -> Result<String, AocError> {
+    const ATTEMPTS: usize = 1000;
+    if !(1..=64).contains(&width) {
+        return Err(AocError::Invalid(format!("Width must be between 1 and 64, got {}", width)));
+    }
+    if count == 0 {
+        return Err(AocError::Invalid("Day 3 needs at least one binary number".to_owned()));
+    }
+    let distinct = width >= 64 || count as u128 <= 1u128 << width;
+    for _ in 0..ATTEMPTS {
+        let mut seen = HashSet::new();
+        let mut values = Vec::with_capacity(count);
+        while values.len() < count {
+            let value = if width == 64 { rng.next_u64() } else { rng.below(1 << width) };
+            if !distinct || seen.insert(value) {
+                values.push(value);
+            }
+        }
+        if has_rating(&values, width, true) && has_rating(&values, width, false) {
+            return Ok(values.iter().map(|value| format!("{:0width$b}\n", value, width = width)).collect());
+        }
+    }
+    Err(AocError::Invalid(format!("No {} binaries of width {} with both ratings after {} attempts", count, width, ATTEMPTS)))
+}
+
+/// Whether keeping the numbers with the most common bit (1 on ties), or the least common one (0 on ties),
+/// from the highest bit down leaves exactly one number.
+fn has_rating(values: &[u64], width: usize, most_common: bool) -> bool {
+    let mut remaining = values.to_vec();
+    for bit in (0..width).rev() {
+        if remaining.len() <= 1 {
+            break;
+        }
+        let ones = remaining.iter().filter(|value| (*value >> bit) & 1 == 1).count();
+        let keep = (ones * 2 >= remaining.len()) == most_common;
+        remaining.retain(|value| ((value >> bit) & 1 == 1) == keep);
+    }
+    remaining.len() == 1
+}
+
+/// All numbers 0 to 99 drawn in random order, so that every board eventually wins, followed by the boards.
+pub fn bingo(rng: &mut Rng, boards: usize) -> String {
+    let mut numbers: Vec<u64> = (0..100).collect();
+    rng.shuffle(&mut numbers);
+    let draws: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    let mut out = draws.join(",") + "\n";
+    for _ in 0..boards {
+        rng.shuffle(&mut numbers);
+        out += "\n";
+        for row in numbers[..25].chunks(5) {
+            let row: Vec<String> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            out += &(row.join(" ") + "\n");
+        }
+    }
+    out
+}
+
+/// Horizontal, vertical and diagonal (at 45 degrees) lines with coordinates below 1000.
+pub fn vents(rng: &mut Rng, count: usize) -> String {
+    const MAX: u64 = 999;
+    let mut out = String::new();
+    for _ in 0..count {
+        let (x1, y1) = (rng.range(0, MAX), rng.range(0, MAX));
+        let (x2, y2) = match rng.below(5) {
+            0 | 1 => (x1, rng.range(0, MAX)),
+            2 | 3 => (rng.range(0, MAX), y1),
+            _ => {
+                let dx = if rng.chance(50) { MAX - x1 } else { x1 };
+                let dy = if rng.chance(50) { MAX - y1 } else { y1 };
+                let length = rng.range(0, dx.min(dy));
+                let x2 = if dx == MAX - x1 { x1 + length } else { x1 - length };
+                let y2 = if dy == MAX - y1 { y1 + length } else { y1 - length };
+                (x2, y2)
+            }
+        };
+        out += &format!("{},{} -> {},{}\n", x1, y1, x2, y2);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::{binaries, generate, Options, Rng, supports};
+
+    #[test]
+    fn same_seed_same_input() {
+        let options = Options { seed: 42, size: Some(50), width: None };
+        for day in 1..=5 {
+            assert_eq!(generate(day, &options).unwrap(), generate(day, &options).unwrap());
+        }
+        let other = Options { seed: 43, ..options };
+        assert_ne!(generate(1, &options).unwrap(), generate(1, &other).unwrap());
+    }
+
+    #[test]
+    fn supported_days() {
+        assert!((1..=5).all(supports));
+        assert!(!supports(0) && !supports(6));
+    }
+
+    #[test]
+    fn rng_range() {
+        let mut rng = Rng::new(7);
+        let values: Vec<u64> = (0..1000).map(|_| rng.range(3, 5)).collect();
+        assert!(values.iter().all(|v| (3..=5).contains(v)));
+        assert!((3..=5).all(|v| values.contains(&v)));
+    }
+
+    #[test]
+    fn distinct_binaries() {
+        let input = binaries(&mut Rng::new(1), 16, 4).unwrap();
+        let mut lines: Vec<&str> = input.lines().collect();
+        assert!(lines.iter().all(|l| l.len() == 4));
+        lines.sort();
+        lines.dedup();
+        assert_eq!(lines.len(), 16);
+    }
+
+    #[test]
+    fn binaries_have_both_ratings() {
+        for seed in 0..50 {
+            let input = binaries(&mut Rng::new(seed), 20, 5).unwrap();
+            let binaries = crate::day03::parse(&input).unwrap();
+            assert!(crate::day03::part2(&binaries).is_ok(), "{}", input);
+        }
+        assert!(binaries(&mut Rng::new(1), 0, 5).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use crate::AocError;
+
+/// Directory with inputs, relative to the crate root.
+pub const INPUT_DIR: &str = "inputs";
+/// Environment variable overriding the directory with inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Environment variable selecting the input profile.
+pub const PROFILE_VAR: &str = "AOC_PROFILE";
+/// Profile with inputs directly in the input directory, the others have their own subdirectory.
+pub const DEFAULT_PROFILE: &str = "default";
+/// Path meaning the standard input.
+pub const STDIN: &str = "-";
+
+/// Resolves a relative path against the crate root, so that it doesn't depend on the working directory.
+pub fn crate_path(path: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+/// Path relative to the crate root when possible, for shorter messages.
+pub fn display_path(path: &Path) -> String {
+    path.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap_or(path).display().to_string()
+}
+
+/// Directory with inputs, [INPUT_DIR_VAR] or `inputs` in the crate root.
+pub fn input_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => crate_path(INPUT_DIR),
+    }
+}
+
+/// Path of the day's input for the profile, `NN.txt` or `<profile>/NN.txt` in the input directory.
+pub fn input_path(day: u8, profile: Option<&str>) -> PathBuf {
+    profile_path(&input_dir(), day, profile)
+}
+
+fn profile_path(dir: &Path, day: u8, profile: Option<&str>) -> PathBuf {
+    let file = format!("{:02}.txt", day);
+    match profile {
+        Some(profile) if profile != DEFAULT_PROFILE => dir.join(profile).join(file),
+        _ => dir.join(file),
+    }
+}
+
+/// Profile selected by [PROFILE_VAR], if any.
+pub fn env_profile() -> Option<String> {
+    env::var(PROFILE_VAR).ok().filter(|p| !p.is_empty())
+}
+
+/// Names of all profiles that have an input for the day, the default profile first.
+pub fn profiles(day: u8) -> Result<Vec<String>, AocError> {
+    profiles_in(&input_dir(), day)
+}
+
+fn profiles_in(dir: &Path, day: u8) -> Result<Vec<String>, AocError> {
+    let entries = fs::read_dir(dir)
+        .map_err(|source| AocError::Io { path: display_path(dir), source })?;
+    let mut profiles: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        // A `default` subdirectory isn't the default profile, whose inputs are in the input directory itself.
+        .filter(|profile| profile != DEFAULT_PROFILE && profile_path(dir, day, Some(profile)).exists())
+        .collect();
+    profiles.sort();
+    if profile_path(dir, day, None).exists() {
+        profiles.insert(0, DEFAULT_PROFILE.to_owned());
+    }
+    Ok(profiles)
+}
+
+/// Reads the file, or the standard input if the path is [STDIN].
+pub fn read_input(path: &Path) -> Result<String, AocError> {
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)
+            .map_err(|source| AocError::Io { path: "standard input".to_owned(), source })?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|source| AocError::Io { path: display_path(path), source })
+    }
+}
+
+/// Opens the file, or the standard input if the path is [STDIN], for reading line by line.
+pub fn open_input(path: &Path) -> Result<Box<dyn BufRead>, AocError> {
+    if path == Path::new(STDIN) {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        let file = File::open(path).map_err(|source| AocError::Io { path: display_path(path), source })?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Reads the input from the path given as the first command line argument, `-` meaning the standard input.
+/// Without it reads the day's input of the profile selected by [PROFILE_VAR].
+pub fn get_input(day: u8) -> Result<String, AocError> {
+    day_input(day, env::args().nth(1).as_deref())
+}
+
+/// Reads the input from the path, if given, otherwise the day's input of the profile selected by [PROFILE_VAR].
+pub fn day_input(day: u8, path: Option<&str>) -> Result<String, AocError> {
+    read_input(&day_input_path(day, path))
+}
+
+/// The path, if given, otherwise path of the day's input of the profile selected by [PROFILE_VAR].
+pub fn day_input_path(day: u8, path: Option<&str>) -> PathBuf {
+    match path {
+        Some(path) => PathBuf::from(path),
+        None => input_path(day, env_profile().as_deref()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use crate::input::{crate_path, display_path, profile_path, profiles_in};
+
+    #[test]
+    fn test_profile_path() {
+        let dir = Path::new("inputs");
+        assert_eq!(profile_path(dir, 5, None), PathBuf::from("inputs/05.txt"));
+        assert_eq!(profile_path(dir, 5, Some("default")), PathBuf::from("inputs/05.txt"));
+        assert_eq!(profile_path(dir, 12, Some("alice")), PathBuf::from("inputs/alice/12.txt"));
+    }
+
+    #[test]
+    fn default_profile_listed_once() {
+        let dir = env::temp_dir().join(format!("aoc2021-profiles-{}", std::process::id()));
+        for profile in ["default", "alice"] {
+            fs::create_dir_all(dir.join(profile)).unwrap();
+            fs::write(profile_path(&dir.join(profile), 3, None), "1\n").unwrap();
+        }
+        fs::write(profile_path(&dir, 3, None), "1\n").unwrap();
+        let profiles = profiles_in(&dir, 3);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(profiles.unwrap(), vec!["default", "alice"]);
+    }
+
+    #[test]
+    fn test_crate_path() {
+        let path = crate_path("inputs/01.txt");
+        assert!(path.is_absolute());
+        assert_eq!(display_path(&path), "inputs/01.txt");
+        assert_eq!(crate_path("/tmp/01.txt"), PathBuf::from("/tmp/01.txt"));
+    }
+}
@@ -1,31 +1,315 @@
-use std::fmt::Debug;
+use std::fmt::{Display, Formatter};
+use std::process::ExitCode;
 use std::str::FromStr;
-use std::{env, fs};
+use std::io;
 
-pub fn as_vec<T>(input: &str) -> Vec<T>
+use crate::bench::Timings;
+use crate::report::TimedAnswer;
+
+pub use day01::Day01;
+pub use input::get_input;
+pub use day02::Day02;
+pub use day03::Day03;
+pub use day04::Day04;
+pub use day05::Day05;
+
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod differential;
+pub mod generate;
+pub mod input;
+pub mod parse;
+pub mod puzzle;
+pub mod reference;
+pub mod report;
+pub mod scaffold;
+pub mod verify;
+
+/// Every implemented day, ordered by day number.
+pub const DAYS: &[Day] = &[
+    Day::of::<Day01>(),
+    Day::of::<Day02>(),
+    Day::of::<Day03>(),
+    Day::of::<Day04>(),
+    Day::of::<Day05>(),
+];
+
+/// Parses every non-blank line of the input.
+pub fn as_vec<T>(input: &str) -> Result<Vec<T>, AocError>
     where
         T: std::str::FromStr,
-        <T as FromStr>::Err: Debug,
+        <T as FromStr>::Err: Display,
+{
+    try_as_vec(input, str::parse)
+}
+
+/// Parses every non-blank line with `parse`. Errors point at the failing line and include its text.
+pub fn try_as_vec<T, E, F>(input: &str, parse: F) -> Result<Vec<T>, AocError>
+    where
+        E: Display,
+        F: Fn(&str) -> Result<T, E>,
 {
     input
         .lines()
-        .map(|l| l.trim())
-        .filter(|l| !l.is_empty())
-        .map(|l| l.parse::<T>().unwrap())
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(idx, l)| {
+            let column = l.chars().take_while(|c| c.is_whitespace()).count() + 1;
+            parse(l.trim()).map_err(|err| value_error(idx + 1, column, l.trim(), err))
+        })
+        .collect()
+}
+
+/// Parses comma separated values, which may be spread over several lines.
+pub fn as_comma_vec<T>(input: &str) -> Result<Vec<T>, AocError>
+    where
+        T: std::str::FromStr,
+        <T as FromStr>::Err: Display,
+{
+    let mut values = Vec::new();
+    for (idx, line) in input.lines().enumerate() {
+        let mut column = 1;
+        for item in line.split(',') {
+            let text = item.trim();
+            if !text.is_empty() {
+                let item_column = column + item.chars().take_while(|c| c.is_whitespace()).count();
+                let value = text.parse().map_err(|err| value_error(idx + 1, item_column, text, err))?;
+                values.push(value);
+            }
+            column += item.chars().count() + 1;
+        }
+    }
+    Ok(values)
+}
+
+/// Splits the input into blocks separated by blank lines and parses each block with `parse`.
+/// Errors point at the first line of the failing block.
+pub fn as_blocks<T, E, F>(input: &str, parse: F) -> Result<Vec<T>, AocError>
+    where
+        E: Display,
+        F: Fn(&str) -> Result<T, E>,
+{
+    let mut blocks = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    for (idx, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current.get_or_insert_with(|| (idx + 1, Vec::new())).1.push(line);
+        }
+    }
+    blocks.extend(current);
+
+    blocks.into_iter()
+        .map(|(line, lines)| {
+            let block = lines.join("\n");
+            parse(&block).map_err(|err| AocError::Parse {
+                line,
+                column: 1,
+                message: format!("Can't parse block starting with {:?}: {}", lines[0].trim(), err),
+            })
+        })
         .collect()
 }
 
-pub fn get_input(default_path: &str) -> String {
-    let path = match env::args().nth(1) {
-        Some(path) => path,
-        None => default_path.to_owned()
-    };
-    fs::read_to_string(path).expect("Failed to load input")
+fn value_error(line: usize, column: usize, text: &str, err: impl Display) -> AocError {
+    AocError::Parse { line, column, message: format!("Can't parse {:?}: {}", text, err) }
+}
+
+/// Prints the error, if any, and turns the result into the process exit code.
+pub fn exit_code(result: Result<(), AocError>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AocError {
+    /// Input file couldn't be read.
+    Io { path: String, source: io::Error },
+    /// Input doesn't match the expected format. Line and column are counted from 1.
+    Parse { line: usize, column: usize, message: String },
+    /// Input is well-formed, but violates puzzle constraints.
+    Invalid(String),
+    /// Input has no answer.
+    NoSolution(String),
+}
+
+impl AocError {
+    /// Parse error located at the start of `rest`, which must be a suffix of `input`.
+    pub fn parse_at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let offset = input.len() - rest.len();
+        let consumed = &input[..offset];
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = consumed[line_start..].chars().count() + 1;
+        AocError::Parse { line, column, message: message.into() }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Io { path, source } => write!(f, "Failed to read {}: {}", path, source),
+            AocError::Parse { line, column, message } =>
+                write!(f, "Parse error at line {}, column {}: {}", line, column, message),
+            AocError::Invalid(message) => write!(f, "Invalid input: {}", message),
+            AocError::NoSolution(message) => write!(f, "No solution: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Solution to a single puzzle day. The input is parsed once and shared by both parts.
+pub trait Solution {
+    /// Day of the puzzle, starting from 1.
+    const DAY: u8;
+    /// Parsed puzzle input.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, AocError>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, AocError>;
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("Invalid part: {}", other)),
+        }
+    }
+}
+
+/// Type-erased [Solution], so that all days can be handled the same way.
+pub struct Day {
+    pub day: u8,
+    solve: fn(&str, &[Part]) -> Result<Vec<String>, AocError>,
+    bench: fn(&str, usize) -> Result<Timings, AocError>,
+    timed: fn(&str, &[Part]) -> Vec<TimedAnswer>,
 }
 
-/// Type-erased errors.
-pub type BoxError = std::boxed::Box<dyn
-std::error::Error   // must implement Error to satisfy ?
-+ std::marker::Send // needed for threads
-+ std::marker::Sync // needed for threads
->;
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Day { day: S::DAY, solve: solve::<S>, bench: bench::bench::<S>, timed: report::timed::<S> }
+    }
+
+    pub fn find(day: u8) -> Option<&'static Day> {
+        DAYS.iter().find(|d| d.day == day)
+    }
+
+    /// Parses the input and returns answers to requested parts, in the same order.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<String>, AocError> {
+        (self.solve)(input, parts)
+    }
+
+    /// Answers to requested parts, each with its own error and the time it took to compute.
+    pub fn timed(&self, input: &str, parts: &[Part]) -> Vec<TimedAnswer> {
+        (self.timed)(input, parts)
+    }
+
+    /// Times parsing and both parts over `runs` repetitions.
+    pub fn bench(&self, input: &str, runs: usize) -> Result<Timings, AocError> {
+        (self.bench)(input, runs)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<String>, AocError> {
+    let parsed = S::parse(input)?;
+    parts.iter()
+        .map(|part| match part {
+            Part::One => S::part1(&parsed).map(|answer| answer.to_string()),
+            Part::Two => S::part2(&parsed).map(|answer| answer.to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{as_blocks, as_comma_vec, as_vec, AocError};
+
+    fn parse_error(result: Result<Vec<u32>, AocError>) -> (usize, usize, String) {
+        match result {
+            Err(AocError::Parse { line, column, message }) => (line, column, message),
+            other => panic!("Expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn as_vec_reports_line() {
+        let (line, column, message) = parse_error(as_vec("1\n\n  x3\n4"));
+        assert_eq!((line, column), (3, 3));
+        assert_eq!(message, r#"Can't parse "x3": invalid digit found in string"#);
+        let (_, column, _) = parse_error(as_vec("\u{a0}\u{a0}x3"));
+        assert_eq!(column, 3);
+    }
+
+    #[test]
+    fn comma_separated() {
+        assert_eq!(as_comma_vec::<u32>("1,2, 3\n4,5,").unwrap(), vec![1, 2, 3, 4, 5]);
+        let (line, column, _) = parse_error(as_comma_vec("1,2\n3, x"));
+        assert_eq!((line, column), (2, 4));
+        let (_, column, _) = parse_error(as_comma_vec("\u{a0}1,\u{a0}x"));
+        assert_eq!(column, 5);
+    }
+
+    #[test]
+    fn blank_line_blocks() {
+        let input = "1,2\n\n1 2\n3 4\n\n\n5 6\n";
+        let blocks: Vec<Vec<u32>> = as_blocks(input, |block| {
+            block.split(|c: char| c == ',' || c.is_whitespace())
+                .map(|n| n.parse::<u32>())
+                .collect()
+        }).unwrap();
+        assert_eq!(blocks, vec![vec![1, 2], vec![1, 2, 3, 4], vec![5, 6]]);
+    }
+
+    #[test]
+    fn block_error_points_at_block() {
+        let result: Result<Vec<u32>, AocError> = as_blocks("1\n\n2\nx", |block| block.parse::<u32>());
+        let (line, _, _) = parse_error(result);
+        assert_eq!(line, 3);
+    }
+}
@@ -1,3 +1,429 @@
-fn main() {
-    println!("Hello, world!");
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::env;
+
+use aoc2021::{bench, Day, DAYS, Part};
+use aoc2021::report::{self, Format, Record};
+use aoc2021::{differential, reference};
+use aoc2021::generate::{generate, Options};
+use aoc2021::input::{crate_path, display_path, env_profile, input_path, profiles, read_input};
+use aoc2021::puzzle::{check_examples, puzzle_path};
+use aoc2021::scaffold::new_day;
+use aoc2021::verify::{Outcome, verify_file};
+
+const USAGE: &str = "Usage:
+    aoc2021 list
+    aoc2021 run <day|all> [part] [--format text|json|csv] [--input path] [--profile name]
+    aoc2021 bench <day|all> [--runs n] [--format table|json] [--input path] [--profile name]
+    aoc2021 profiles <day|all> [part]
+    aoc2021 verify [--answers path]
+    aoc2021 examples [day|all]
+    aoc2021 new <day>
+    aoc2021 gen <day> [--size n] [--seed n] [--width bits]
+    aoc2021 diff <day|all> [--seeds n] [--size n] [--seed first]
+
+Inputs are read from `inputs` in the crate root, or from AOC_INPUT_DIR. Use `--input -` to read the standard input.";
+
+const DEFAULT_ANSWERS: &str = "answers.txt";
+
+const DEFAULT_RUNS: usize = 10;
+
+const DEFAULT_SEEDS: u64 = 100;
+
+const DEFAULT_DIFF_SIZE: usize = 50;
+
+enum Selection {
+    All,
+    Day(u8),
+}
+
+/// Where to read the input from. Explicit path wins over the profile, which defaults to `AOC_PROFILE`.
+struct InputArgs {
+    path: Option<String>,
+    profile: Option<String>,
+}
+
+impl InputArgs {
+    fn new() -> Self {
+        InputArgs { path: None, profile: env_profile() }
+    }
+
+    /// Consumes the value of an input flag. Returns false if the argument isn't an input flag.
+    fn parse_flag<'a>(&mut self, arg: &str, iter: &mut impl Iterator<Item=&'a String>) -> Result<bool, String> {
+        match arg {
+            "--input" => self.path = Some(iter.next().ok_or("--input requires a path")?.clone()),
+            "--profile" => self.profile = Some(iter.next().ok_or("--profile requires a name")?.clone()),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn check(&self, selection: &Selection) -> Result<(), String> {
+        if self.path.is_some() && matches!(selection, Selection::All) {
+            Err("--input can't be used when running all days".to_owned())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn path(&self, day: &Day) -> PathBuf {
+        match &self.path {
+            Some(path) => PathBuf::from(path),
+            None => input_path(day.day, self.profile.as_deref()),
+        }
+    }
+
+    fn read(&self, day: &Day) -> Result<String, String> {
+        read_input(&self.path(day)).map_err(|err| err.to_string())
+    }
+}
+
+struct RunArgs {
+    selection: Selection,
+    part: Option<Part>,
+    format: Format,
+    input: InputArgs,
+}
+
+enum BenchFormat {
+    Table,
+    Json,
+}
+
+struct BenchArgs {
+    selection: Selection,
+    runs: usize,
+    format: BenchFormat,
+    input: InputArgs,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("list") => {
+            list();
+            Ok(())
+        }
+        Some("run") => parse_run_args(&args[1..]).and_then(|run_args| run(&run_args)),
+        Some("bench") => parse_bench_args(&args[1..]).and_then(|bench_args| run_bench(&bench_args)),
+        Some("profiles") => run_profiles(&args[1..]),
+        Some("verify") => parse_verify_args(&args[1..]).and_then(run_verify),
+        Some("examples") => run_examples(&args[1..]),
+        Some("new") => run_new(&args[1..]),
+        Some("gen") => run_gen(&args[1..]),
+        Some("diff") => run_diff(&args[1..]),
+        _ => Err(USAGE.to_owned()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list() {
+    for day in DAYS {
+        println!("Day {:02}: parts 1, 2", day.day);
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut positional = Vec::new();
+    let mut format = Format::Text;
+    let mut input = InputArgs::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if input.parse_flag(arg, &mut iter)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--format" => format = iter.next().ok_or("--format requires a value")?.parse()?,
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let selection = parse_selection(positional.first())?;
+    let part = positional.get(1).map(|part| part.parse()).transpose()?;
+    if positional.len() > 2 {
+        return Err(USAGE.to_owned());
+    }
+    input.check(&selection)?;
+
+    Ok(RunArgs { selection, part, format, input })
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut positional = Vec::new();
+    let mut runs = DEFAULT_RUNS;
+    let mut format = BenchFormat::Table;
+    let mut input = InputArgs::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if input.parse_flag(arg, &mut iter)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--runs" => {
+                let value = iter.next().ok_or("--runs requires a number")?;
+                runs = value.parse().ok().filter(|r| *r > 0)
+                    .ok_or(format!("Invalid number of runs: {}", value))?;
+            }
+            "--format" => {
+                format = match iter.next().map(|s| s.as_str()) {
+                    Some("table") => BenchFormat::Table,
+                    Some("json") => BenchFormat::Json,
+                    other => return Err(format!("Invalid format: {}", other.unwrap_or(""))),
+                };
+            }
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    if positional.len() > 1 {
+        return Err(USAGE.to_owned());
+    }
+    let selection = parse_selection(positional.first())?;
+    input.check(&selection)?;
+
+    Ok(BenchArgs { selection, runs, format, input })
+}
+
+fn parse_selection(arg: Option<&&str>) -> Result<Selection, String> {
+    match arg {
+        Some(&"all") => Ok(Selection::All),
+        Some(day) => Ok(Selection::Day(day.parse().map_err(|_| format!("Invalid day: {}", day))?)),
+        None => Err(USAGE.to_owned()),
+    }
+}
+
+fn selected_days(selection: &Selection) -> Result<Vec<&'static Day>, String> {
+    match selection {
+        Selection::All => Ok(DAYS.iter().collect()),
+        Selection::Day(day) => Ok(vec![Day::find(*day).ok_or(format!("Day {} is not solved yet", day))?]),
+    }
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    let days = selected_days(&args.selection)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let mut records = Vec::new();
+    for day in days {
+        let path = args.input.path(day);
+        let answers = match args.input.read(day) {
+            Ok(input) => day.timed(&input, &parts),
+            Err(err) => parts.iter().map(|_| (Err(err.clone()), Duration::default())).collect(),
+        };
+        for (part, (answer, elapsed)) in parts.iter().zip(answers) {
+            let input = display_path(&path);
+            records.push(Record { day: day.day, part: *part, input, answer, elapsed });
+        }
+    }
+
+    print!("{}", report::render(&records, args.format));
+    let failures = records.iter().filter(|r| r.answer.is_err()).count();
+    if failures > 0 {
+        Err(format!("{} of {} parts failed", failures, records.len()))
+    } else {
+        Ok(())
+    }
+}
+
+fn run_bench(args: &BenchArgs) -> Result<(), String> {
+    let mut timings = Vec::new();
+    for day in selected_days(&args.selection)? {
+        let input = args.input.read(day)?;
+        let day_timings = day.bench(&input, args.runs)
+            .map_err(|err| format!("Day {:02} failed: {}", day.day, err))?;
+        timings.push(day_timings);
+    }
+
+    match args.format {
+        BenchFormat::Table => print!("{}", bench::table(&timings)),
+        BenchFormat::Json => print!("{}", bench::json(&timings)),
+    }
+    Ok(())
+}
+
+fn run_profiles(args: &[String]) -> Result<(), String> {
+    let (selection, parts) = match args {
+        [day] => (parse_selection(Some(&day.as_str()))?, Part::BOTH.to_vec()),
+        [day, part] => (parse_selection(Some(&day.as_str()))?, vec![part.parse()?]),
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    for day in selected_days(&selection)? {
+        let header: Vec<String> = parts.iter().map(|part| format!("{:<16}", format!("Part {}", part))).collect();
+        println!("Day {:02}", day.day);
+        println!("{:<16}{}", "Profile", header.join("").trim_end());
+        for profile in profiles(day.day).map_err(|err| err.to_string())? {
+            let answers = read_input(&input_path(day.day, Some(&profile)))
+                .and_then(|input| day.solve(&input, &parts));
+            let cells = match answers {
+                Ok(answers) => answers.iter().map(|a| format!("{:<16}", a)).collect::<String>(),
+                Err(err) => format!("error: {}", err.to_string().lines().next().unwrap_or("")),
+            };
+            println!("{:<16}{}", profile, cells.trim_end());
+        }
+    }
+    Ok(())
+}
+
+fn parse_verify_args(args: &[String]) -> Result<PathBuf, String> {
+    match args {
+        [] => Ok(crate_path(DEFAULT_ANSWERS)),
+        [flag, path] if flag == "--answers" => Ok(PathBuf::from(path)),
+        _ => Err(USAGE.to_owned()),
+    }
+}
+
+fn run_verify(answers_path: PathBuf) -> Result<(), String> {
+    let checks = verify_file(&answers_path).map_err(|err| err.to_string())?;
+    let mut failures = 0;
+    for check in &checks {
+        let expected = &check.expected;
+        if check.outcome != Outcome::Correct {
+            failures += 1;
+        }
+        println!("Day {:02} part {} ({}): {}", expected.day, expected.part, display_path(&expected.input_path()),
+                 status(&check.outcome, &expected.answer));
+    }
+
+    if failures > 0 {
+        Err(format!("{} of {} answers don't match", failures, checks.len()))
+    } else {
+        println!("All {} answers match", checks.len());
+        Ok(())
+    }
+}
+
+fn run_examples(args: &[String]) -> Result<(), String> {
+    let selection = match args {
+        [] => Selection::All,
+        [day] => parse_selection(Some(&day.as_str()))?,
+        _ => return Err(USAGE.to_owned()),
+    };
+    let days = selected_days(&selection)?;
+
+    let mut total = 0;
+    let mut failures = 0;
+    for day in days {
+        if matches!(selection, Selection::All) && !puzzle_path(day.day).exists() {
+            continue;
+        }
+        for (example, outcome) in check_examples(day).map_err(|err| err.to_string())? {
+            total += 1;
+            if outcome != Outcome::Correct {
+                failures += 1;
+            }
+            println!("Day {:02} part {} example: {}", day.day, example.part, status(&outcome, &example.answer));
+        }
+    }
+
+    if failures > 0 {
+        Err(format!("{} of {} examples don't match", failures, total))
+    } else {
+        println!("All {} examples match", total);
+        Ok(())
+    }
+}
+
+fn status(outcome: &Outcome, expected: &str) -> String {
+    match outcome {
+        Outcome::Correct => "ok".to_owned(),
+        Outcome::Mismatch(actual) => format!("MISMATCH expected {}, got {}", expected, actual),
+        Outcome::Failed(err) => format!("FAILED {}", err),
+    }
+}
+
+fn run_new(args: &[String]) -> Result<(), String> {
+    let day = match args {
+        [day] => day.parse().map_err(|_| format!("Invalid day: {}", day))?,
+        _ => return Err(USAGE.to_owned()),
+    };
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let written = new_day(&root, day).map_err(|err| err.to_string())?;
+    for path in written {
+        println!("Wrote {}", path.strip_prefix(&root).unwrap_or(&path).display());
+    }
+    Ok(())
+}
+
+fn run_gen(args: &[String]) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut seed = None;
+    let mut options = Options { seed: 0, size: None, width: None };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seed" => seed = Some(number_flag(arg, iter.next())?),
+            "--size" => options.size = Some(number_flag(arg, iter.next())?),
+            "--width" => options.width = Some(number_flag(arg, iter.next())?),
+            _ => positional.push(arg.as_str()),
+        }
+    }
+    let day = match positional.as_slice() {
+        [day] => day.parse().map_err(|_| format!("Invalid day: {}", day))?,
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    // Without an explicit seed, print the one used so that an interesting input can be generated again.
+    options.seed = match seed {
+        Some(seed) => seed,
+        None => {
+            let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or_default();
+            eprintln!("Seed: {}", seed);
+            seed
+        }
+    };
+    print!("{}", generate(day, &options).map_err(|err| err.to_string())?);
+    Ok(())
+}
+
+fn run_diff(args: &[String]) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut first = 0;
+    let mut seeds = DEFAULT_SEEDS;
+    let mut size = DEFAULT_DIFF_SIZE;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seed" => first = number_flag(arg, iter.next())?,
+            "--seeds" => seeds = number_flag(arg, iter.next())?,
+            "--size" => size = number_flag(arg, iter.next())?,
+            _ => positional.push(arg.as_str()),
+        }
+    }
+    if positional.len() != 1 {
+        return Err(USAGE.to_owned());
+    }
+
+    for day in selected_days(&parse_selection(positional.first())?)? {
+        if reference::for_day(day.day).is_none() {
+            println!("Day {:02}: no reference solution, skipped", day.day);
+            continue;
+        }
+        // Shrinking a failing input runs the solution over and over, one panic message each time would bury the result.
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+        let result = differential::check(day, first..first.saturating_add(seeds), size);
+        std::panic::set_hook(hook);
+        match result.map_err(|err| err.to_string())? {
+            None => println!("Day {:02}: {} inputs agree with the reference", day.day, seeds),
+            Some(disagreement) => return Err(disagreement.to_string()),
+        }
+    }
+    Ok(())
+}
+
+fn number_flag<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or(format!("{} requires a number", flag))?;
+    value.parse().map_err(|_| format!("Invalid value of {}: {}", flag, value))
 }
@@ -0,0 +1,180 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, line_ending, multispace0, space0};
+use nom::combinator::eof;
+use nom::error::{context, VerboseError, VerboseErrorKind};
+use nom::multi::{count, separated_list1};
+use nom::sequence::{delimited, preceded, separated_pair};
+use nom::IResult;
+
+use crate::AocError;
+
+pub type ParseResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// One or more items separated by commas, e.g. `7,4,9,5`.
+pub fn comma_list<'a, T, F>(item: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>>
+    where F: FnMut(&'a str) -> ParseResult<'a, T>
+{
+    separated_list1(delimited(space0, char(','), space0), item)
+}
+
+/// Exactly `width * height` items separated by any whitespace, in row-major order.
+pub fn grid<'a, T, F>(width: usize, height: usize, item: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>>
+    where F: FnMut(&'a str) -> ParseResult<'a, T>
+{
+    count(preceded(multispace0, item), width * height)
+}
+
+/// Two items separated by an arrow, e.g. `0,9 -> 5,9`.
+pub fn arrow_pair<'a, A, B, FA, FB>(first: FA, second: FB) -> impl FnMut(&'a str) -> ParseResult<'a, (A, B)>
+    where FA: FnMut(&'a str) -> ParseResult<'a, A>,
+          FB: FnMut(&'a str) -> ParseResult<'a, B>
+{
+    separated_pair(first, delimited(space0, tag("->"), space0), second)
+}
+
+/// One item per line until the end of input. Blank lines are skipped.
+/// Unlike `many0`, an item that fails to parse is reported instead of ending the list.
+pub fn lines<'a, T, F>(mut item: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>>
+    where F: FnMut(&'a str) -> ParseResult<'a, T>
+{
+    move |mut s: &'a str| {
+        let mut items = Vec::new();
+        loop {
+            let (rest, _) = multispace0(s)?;
+            if rest.is_empty() {
+                return Ok((rest, items));
+            }
+            let (rest, value) = item(rest)?;
+            let (rest, _) = context("end of line", preceded(space0, alt((line_ending, eof))))(rest)?;
+            items.push(value);
+            s = rest;
+        }
+    }
+}
+
+/// Items separated by any whitespace until the end of input.
+/// Unlike `many0`, an item that fails to parse is reported instead of ending the list.
+pub fn blocks<'a, T, F>(mut item: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>>
+    where F: FnMut(&'a str) -> ParseResult<'a, T>
+{
+    move |mut s: &'a str| {
+        let mut items = Vec::new();
+        loop {
+            let (rest, _) = multispace0(s)?;
+            if rest.is_empty() {
+                return Ok((rest, items));
+            }
+            let (rest, value) = item(rest)?;
+            items.push(value);
+            s = rest;
+        }
+    }
+}
+
+/// Runs the parser on the whole input, ignoring surrounding whitespace.
+/// Leftover input is an error, just like a failure of the parser itself.
+pub fn parse_all<'a, T, F>(input: &'a str, parser: F) -> Result<T, AocError>
+    where F: FnMut(&'a str) -> ParseResult<'a, T>
+{
+    let mut full = delimited(multispace0, parser, preceded(multispace0, context("end of input", eof)));
+    match full(input) {
+        Ok((_, value)) => Ok(value),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => Err(report(input, &e)),
+        Err(nom::Err::Incomplete(_)) => Err(located(input, "", "unexpected end of input")),
+    }
+}
+
+/// Converts the error into [AocError::Parse] pointing at the innermost failure.
+/// The message shows the offending line with a caret under the failing column.
+pub fn report(input: &str, err: &VerboseError<&str>) -> AocError {
+    let (rest, kind) = match err.errors.first() {
+        Some(first) => first,
+        None => return located(input, "", "invalid input"),
+    };
+    let expected = err.errors.iter()
+        .find_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(ctx) => Some(format!("expected {}", ctx)),
+            _ => None,
+        });
+    let message = expected.unwrap_or_else(|| describe(kind));
+    located(input, rest, &message)
+}
+
+fn describe(kind: &VerboseErrorKind) -> String {
+    use nom::error::ErrorKind;
+    match kind {
+        VerboseErrorKind::Char(c) => format!("expected '{}'", c),
+        VerboseErrorKind::Context(ctx) => format!("expected {}", ctx),
+        VerboseErrorKind::Nom(ErrorKind::Digit) => "expected a number".to_owned(),
+        VerboseErrorKind::Nom(ErrorKind::Eof) => "expected end of input".to_owned(),
+        VerboseErrorKind::Nom(ErrorKind::Tag) => "unexpected input".to_owned(),
+        VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+    }
+}
+
+fn located(input: &str, rest: &str, message: &str) -> AocError {
+    match AocError::parse_at(input, rest, message) {
+        AocError::Parse { line, column, message } => {
+            let text = input.lines().nth(line - 1).unwrap_or("");
+            let message = format!("{}\n{}", message, caret(line, column, text));
+            AocError::Parse { line, column, message }
+        }
+        other => other,
+    }
+}
+
+/// Formats the line with a caret under the column, both counted from 1.
+pub fn caret(line: usize, column: usize, text: &str) -> String {
+    let gutter = line.to_string();
+    let padding: String = text.chars()
+        .take(column - 1)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    format!("{} | {}\n{} | {}^", gutter, text, " ".repeat(gutter.len()), padding)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::u16 as p_u16;
+    use nom::error::context;
+
+    use crate::AocError;
+    use crate::parse::{arrow_pair, comma_list, grid, lines, parse_all};
+
+    #[test]
+    fn test_comma_list() {
+        assert_eq!(parse_all("1,2 , 3", comma_list(p_u16)).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = " 1 2\n3  4\n";
+        assert_eq!(parse_all(input, grid(2, 2, p_u16)).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_lines_of_pairs() {
+        let input = "\n1 -> 2\n\n3->4\n";
+        assert_eq!(parse_all(input, lines(arrow_pair(p_u16, p_u16))).unwrap(), vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn error_report() {
+        let input = "1 -> 2\n3 -> x4\n";
+        let number = |s| context("number", p_u16)(s);
+        match parse_all(input, lines(arrow_pair(number, number))) {
+            Err(AocError::Parse { line, column, message }) => {
+                assert_eq!((line, column), (2, 6));
+                assert_eq!(message, "expected number\n2 | 3 -> x4\n  |      ^");
+            }
+            other => panic!("Expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn leftover_input() {
+        let result = parse_all("1,2\n3", comma_list(p_u16));
+        assert!(matches!(result, Err(AocError::Parse { line: 2, column: 1, .. })));
+    }
+}
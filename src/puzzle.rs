@@ -0,0 +1,134 @@
+use std::path::PathBuf;
+
+use crate::{AocError, Day, Part};
+use crate::input::{crate_path, read_input};
+use crate::verify::{compare, Outcome};
+
+const PART_TWO_HEADER: &str = "## --- Part Two ---";
+const ANSWER_PREFIX: &str = "Your puzzle answer was";
+
+/// Example input together with the answer the puzzle text gives for it.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Extracts examples from the puzzle markdown.
+///
+/// The example input is the first code block of the puzzle and it's shared by both parts, since blocks of
+/// the second part usually only illustrate the calculation. The expected answer of a part is the last
+/// highlighted number in its text, e.g. *`7`* or `*150*`.
+pub fn examples(markdown: &str) -> Vec<Example> {
+    let (part1, part2) = match markdown.split_once(PART_TWO_HEADER) {
+        Some((part1, part2)) => (part1, Some(part2)),
+        None => (markdown, None),
+    };
+    let input = match code_blocks(part1).into_iter().next() {
+        Some(input) => input,
+        None => return Vec::new(),
+    };
+
+    [(Part::One, Some(part1)), (Part::Two, part2)].into_iter()
+        .filter_map(|(part, text)| {
+            let answer = highlighted_numbers(text?).pop()?;
+            Some(Example { part, input: input.clone(), answer })
+        })
+        .collect()
+}
+
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match current.take() {
+                Some(lines) => blocks.push(lines.join("\n") + "\n"),
+                None => current = Some(Vec::new()),
+            }
+        } else if let Some(lines) = current.as_mut() {
+            lines.push(line);
+        }
+    }
+    blocks
+}
+
+/// Numbers emphasised with `*` either inside or outside of inline code, in order of appearance.
+/// Text after the recorded puzzle answer is ignored.
+pub fn highlighted_numbers(markdown: &str) -> Vec<String> {
+    let text = markdown.split(ANSWER_PREFIX).next().unwrap_or("");
+    ["*`", "`*"].iter()
+        .flat_map(|open| {
+            let close: String = open.chars().rev().collect();
+            text.match_indices(open)
+                .filter_map(move |(idx, _)| {
+                    let start = idx + open.len();
+                    let len = text[start..].find(close.as_str())?;
+                    let inner = &text[start..start + len];
+                    let is_number = !inner.is_empty() && inner.chars().all(|c| c.is_ascii_digit() || c == '-');
+                    is_number.then(|| (idx, inner.to_owned()))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<std::collections::BTreeMap<_, _>>()
+        .into_values()
+        .collect()
+}
+
+pub fn puzzle_path(day: u8) -> PathBuf {
+    crate_path(format!("puzzle/{:02}.md", day))
+}
+
+/// Solves every example from the puzzle text of the day.
+pub fn check_examples(day: &Day) -> Result<Vec<(Example, Outcome)>, AocError> {
+    let markdown = read_input(&puzzle_path(day.day))?;
+    let checks = examples(&markdown).into_iter()
+        .map(|example| {
+            let outcome = compare(day, &example.input, example.part, &example.answer);
+            (example, outcome)
+        })
+        .collect();
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Part;
+    use crate::puzzle::{Example, examples, highlighted_numbers};
+
+    #[test]
+    fn test_highlighted_numbers() {
+        let text = "gamma is `*22*`, there are *`7`* of them, `*0*` and *fifty* `123`";
+        assert_eq!(highlighted_numbers(text), vec!["22", "7", "0"]);
+    }
+
+    #[test]
+    fn test_examples() {
+        let markdown = r#"
+## --- Day 0: Test ---
+
+```
+1
+2
+```
+
+Sum is `*3*`.
+
+Your puzzle answer was `*10*`.
+
+## --- Part Two ---
+
+```
+diagram
+```
+
+Product is *`2`*.
+"#;
+        let expected = vec![
+            Example { part: Part::One, input: "1\n2\n".to_owned(), answer: "3".to_owned() },
+            Example { part: Part::Two, input: "1\n2\n".to_owned(), answer: "2".to_owned() },
+        ];
+        assert_eq!(examples(markdown), expected);
+    }
+}
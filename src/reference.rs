@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+/// Brute-force solution of a day, returning answers to both parts.
+pub type Reference = fn(&str) -> Result<Vec<String>, String>;
+
+/// Deliberately simple solutions, written independently of the real ones to cross-check them.
+/// They parse the input on their own, favour obviousness over speed and use wide integers.
+pub fn for_day(day: u8) -> Option<Reference> {
+    match day {
+        1 => Some(day01),
+        2 => Some(day02),
+        3 => Some(day03),
+        4 => Some(day04),
+        5 => Some(day05),
+        _ => None,
+    }
+}
+
+fn numbers(text: &str, separator: char) -> Result<Vec<i64>, String> {
+    text.split(separator)
+        .map(|n| n.trim())
+        .filter(|n| !n.is_empty())
+        .map(|n| n.parse().map_err(|_| format!("Not a number: {:?}", n)))
+        .collect()
+}
+
+fn non_blank_lines(input: &str) -> Vec<&str> {
+    input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()).collect()
+}
+
+fn count_increases(values: &[i64]) -> usize {
+    (1..values.len()).filter(|&i| values[i] > values[i - 1]).count()
+}
+
+fn day01(input: &str) -> Result<Vec<String>, String> {
+    let depths = numbers(input, '\n')?;
+    let mut sums = Vec::new();
+    for i in 2..depths.len() {
+        sums.push(depths[i - 2] + depths[i - 1] + depths[i]);
+    }
+    Ok(vec![count_increases(&depths).to_string(), count_increases(&sums).to_string()])
+}
+
+fn day02(input: &str) -> Result<Vec<String>, String> {
+    let (mut horizontal, mut depth) = (0i64, 0i64);
+    let (mut aim_horizontal, mut aim_depth, mut aim) = (0i64, 0i64, 0i64);
+    for line in non_blank_lines(input) {
+        let (command, value) = line.split_once(' ').ok_or(format!("Invalid command: {:?}", line))?;
+        let value: i64 = value.parse().map_err(|_| format!("Invalid value: {:?}", line))?;
+        match command {
+            "forward" => {
+                horizontal += value;
+                aim_horizontal += value;
+                aim_depth += aim * value;
+            }
+            "down" => {
+                depth += value;
+                aim += value;
+            }
+            "up" => {
+                depth -= value;
+                aim -= value;
+            }
+            _ => return Err(format!("Unknown command: {:?}", line)),
+        }
+        if depth < 0 || aim < 0 {
+            return Err("Submarine went above the surface".to_owned());
+        }
+    }
+    Ok(vec![(horizontal * depth).to_string(), (aim_horizontal * aim_depth).to_string()])
+}
+
+fn day03(input: &str) -> Result<Vec<String>, String> {
+    let report = non_blank_lines(input);
+    let width = report.first().ok_or("Empty report")?.len();
+    if report.iter().any(|b| b.len() != width || b.chars().any(|c| c != '0' && c != '1')) {
+        return Err("Invalid report".to_owned());
+    }
+    let ones = |numbers: &[&str], bit: usize| numbers.iter().filter(|b| b.as_bytes()[bit] == b'1').count();
+
+    let mut gamma = String::new();
+    let mut epsilon = String::new();
+    for bit in 0..width {
+        let more_ones = ones(&report, bit) * 2 > report.len();
+        gamma.push(if more_ones { '1' } else { '0' });
+        epsilon.push(if more_ones { '0' } else { '1' });
+    }
+
+    // Oxygen keeps the most common bit, 1 on ties. Scrubber keeps the least common bit, 0 on ties.
+    let rating = |most_common: bool| -> Result<String, String> {
+        let mut remaining = report.clone();
+        for bit in 0..width {
+            if remaining.len() == 1 {
+                break;
+            }
+            let ones = ones(&remaining, bit);
+            let zeros = remaining.len() - ones;
+            let keep = match (most_common, ones >= zeros) {
+                (true, true) | (false, false) => b'1',
+                _ => b'0',
+            };
+            remaining.retain(|b| b.as_bytes()[bit] == keep);
+        }
+        match remaining.as_slice() {
+            [rating] => Ok(rating.to_string()),
+            [] => Err("No number left for the rating".to_owned()),
+            _ => Err("Rating is ambiguous".to_owned()),
+        }
+    };
+    let decimal = |b: &str| i64::from_str_radix(b, 2).map_err(|e| e.to_string());
+
+    let part1 = decimal(&gamma)? * decimal(&epsilon)?;
+    let part2 = decimal(&rating(true)?)? * decimal(&rating(false)?)?;
+    Ok(vec![part1.to_string(), part2.to_string()])
+}
+
+fn day04(input: &str) -> Result<Vec<String>, String> {
+    let mut blocks = input.split("\n\n").map(|b| b.trim()).filter(|b| !b.is_empty());
+    let draws = numbers(blocks.next().ok_or("Empty input")?, ',')?;
+    let boards: Vec<Vec<i64>> = blocks.map(|b| numbers(&b.replace('\n', " "), ' ')).collect::<Result<_, _>>()?;
+    if boards.iter().any(|b| b.len() != 25) {
+        return Err("Board must have 25 numbers".to_owned());
+    }
+
+    let mut marked = vec![[false; 25]; boards.len()];
+    let mut won = vec![false; boards.len()];
+    let mut scores = Vec::new();
+    for draw in draws {
+        for (board, numbers) in boards.iter().enumerate() {
+            if won[board] {
+                continue;
+            }
+            for (cell, number) in numbers.iter().enumerate() {
+                if *number == draw {
+                    marked[board][cell] = true;
+                }
+            }
+            let m = &marked[board];
+            let row = (0..5).any(|r| (0..5).all(|c| m[r * 5 + c]));
+            let column = (0..5).any(|c| (0..5).all(|r| m[r * 5 + c]));
+            if row || column {
+                won[board] = true;
+                let unmarked: i64 = (0..25).filter(|cell| !m[*cell]).map(|cell| numbers[cell]).sum();
+                scores.push(unmarked * draw);
+            }
+        }
+    }
+    match (scores.first(), scores.last()) {
+        (Some(first), Some(last)) => Ok(vec![first.to_string(), last.to_string()]),
+        _ => Err("No board wins".to_owned()),
+    }
+}
+
+fn day05(input: &str) -> Result<Vec<String>, String> {
+    let mut straight: HashMap<(i64, i64), u32> = HashMap::new();
+    let mut all: HashMap<(i64, i64), u32> = HashMap::new();
+    for line in non_blank_lines(input) {
+        let (from, to) = line.split_once("->").ok_or(format!("Invalid line: {:?}", line))?;
+        let (from, to) = (numbers(from, ',')?, numbers(to, ',')?);
+        let (x1, y1, x2, y2) = match (from.as_slice(), to.as_slice()) {
+            ([x1, y1], [x2, y2]) => (*x1, *y1, *x2, *y2),
+            _ => return Err(format!("Invalid line: {:?}", line)),
+        };
+        let steps = (x2 - x1).abs().max((y2 - y1).abs());
+        for step in 0..=steps {
+            let point = (x1 + step * (x2 - x1).signum(), y1 + step * (y2 - y1).signum());
+            if x1 == x2 || y1 == y2 {
+                *straight.entry(point).or_default() += 1;
+            }
+            *all.entry(point).or_default() += 1;
+        }
+    }
+    let overlaps = |points: &HashMap<(i64, i64), u32>| points.values().filter(|n| **n > 1).count();
+    Ok(vec![overlaps(&straight).to_string(), overlaps(&all).to_string()])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reference::for_day;
+
+    #[test]
+    fn bingo_sample() {
+        let input = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+        assert_eq!(for_day(4).unwrap()(input).unwrap(), vec!["4512", "1924"]);
+    }
+
+    #[test]
+    fn vents_sample() {
+        let input = "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n\
+                     6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2\n";
+        assert_eq!(for_day(5).unwrap()(input).unwrap(), vec!["5", "12"]);
+    }
+}
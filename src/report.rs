@@ -0,0 +1,172 @@
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::{Part, Solution};
+
+/// Answer to a single part, or the error that prevented it.
+#[derive(Debug)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    /// Where the input was read from, `-` for the standard input.
+    pub input: String,
+    pub answer: Result<String, String>,
+    /// Time spent on the part alone, without reading and parsing the input.
+    pub elapsed: Duration,
+}
+
+/// Answer to a part, or the error message, with the time spent computing it.
+pub type TimedAnswer = (Result<String, String>, Duration);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!("Invalid format: {}", other)),
+        }
+    }
+}
+
+/// Answers to requested parts together with the time each of them took.
+/// If the input can't be parsed, every part fails with the parse error.
+pub(crate) fn timed<S: Solution>(input: &str, parts: &[Part]) -> Vec<TimedAnswer> {
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(err) => return parts.iter().map(|_| (Err(err.to_string()), Duration::default())).collect(),
+    };
+    parts.iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&parsed).map(|answer| answer.to_string()),
+                Part::Two => S::part2(&parsed).map(|answer| answer.to_string()),
+            };
+            (answer.map_err(|err| err.to_string()), start.elapsed())
+        })
+        .collect()
+}
+
+pub fn render(records: &[Record], format: Format) -> String {
+    match format {
+        Format::Text => text(records),
+        Format::Json => json(records),
+        Format::Csv => csv(records),
+    }
+}
+
+fn text(records: &[Record]) -> String {
+    records.iter()
+        .map(|r| match &r.answer {
+            Ok(answer) => format!("Day {:02} part {}: {}\n", r.day, r.part, answer),
+            Err(err) => format!("Day {:02} part {} failed: {}\n", r.day, r.part, err),
+        })
+        .collect()
+}
+
+fn json(records: &[Record]) -> String {
+    let records: Vec<String> = records.iter()
+        .map(|r| {
+            let (answer, error) = match &r.answer {
+                Ok(answer) => (json_string(answer), "null".to_owned()),
+                Err(err) => ("null".to_owned(), json_string(err)),
+            };
+            format!(r#"  {{"day": {}, "part": {}, "answer": {}, "input": {}, "elapsed_ns": {}, "error": {}}}"#,
+                    r.day, r.part, answer, json_string(&r.input), r.elapsed.as_nanos(), error)
+        })
+        .collect();
+    if records.is_empty() {
+        "[]\n".to_owned()
+    } else {
+        format!("[\n{}\n]\n", records.join(",\n"))
+    }
+}
+
+fn csv(records: &[Record]) -> String {
+    let mut out = "day,part,answer,input,elapsed_ns,error\n".to_owned();
+    for r in records {
+        let (answer, error) = match &r.answer {
+            Ok(answer) => (answer.as_str(), ""),
+            Err(err) => ("", err.as_str()),
+        };
+        out += &format!("{},{},{},{},{},{}\n",
+                        r.day, r.part, csv_field(answer), csv_field(&r.input), r.elapsed.as_nanos(), csv_field(error));
+    }
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            '\t' => out += "\\t",
+            c if (c as u32) < 0x20 => out += &format!("\\u{:04x}", c as u32),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quotes the field if it contains a separator, a quote or a line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::Part;
+    use crate::report::{Format, Record, render};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record { day: 4, part: Part::One, input: "inputs/04.txt".to_owned(), answer: Ok("54275".to_owned()), elapsed: Duration::from_nanos(1500) },
+            Record { day: 4, part: Part::Two, input: "-".to_owned(), answer: Err("No solution: \"x\",\ny".to_owned()), elapsed: Duration::ZERO },
+        ]
+    }
+
+    #[test]
+    fn json_records() {
+        let expected = r#"[
+  {"day": 4, "part": 1, "answer": "54275", "input": "inputs/04.txt", "elapsed_ns": 1500, "error": null},
+  {"day": 4, "part": 2, "answer": null, "input": "-", "elapsed_ns": 0, "error": "No solution: \"x\",\ny"}
+]
+"#;
+        assert_eq!(render(&records(), Format::Json), expected);
+    }
+
+    #[test]
+    fn csv_records() {
+        let expected = "day,part,answer,input,elapsed_ns,error\n\
+                        4,1,54275,inputs/04.txt,1500,\n\
+                        4,2,,-,0,\"No solution: \"\"x\"\",\ny\"\n";
+        assert_eq!(render(&records(), Format::Csv), expected);
+    }
+
+    #[test]
+    fn text_records() {
+        assert_eq!(render(&records(), Format::Text),
+                   "Day 04 part 1: 54275\nDay 04 part 2 failed: No solution: \"x\",\ny\n");
+    }
+}
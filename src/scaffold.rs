@@ -0,0 +1,129 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::AocError;
+
+/// Files of a new day as (path relative to the crate root, template).
+const TEMPLATES: &[(&str, &str)] = &[
+    ("src/day__DD__/mod.rs", include_str!("../templates/day/mod.rs")),
+    ("src/day__DD__/model.rs", include_str!("../templates/day/model.rs")),
+    ("src/day__DD__/parser.rs", include_str!("../templates/day/parser.rs")),
+    ("src/day__DD__/part1.rs", include_str!("../templates/day/part1.rs")),
+    ("src/day__DD__/part2.rs", include_str!("../templates/day/part2.rs")),
+    ("src/bin/day__DD__/main.rs", include_str!("../templates/bin/main.rs")),
+    ("inputs/__DD__.txt", ""),
+];
+
+/// Lines registering a day in lib.rs. Each is inserted after the last line registering an existing day.
+const REGISTRATIONS: &[&str] = &[
+    "pub use day__DD__::Day__DD__;",
+    "pub mod day__DD__;",
+    "    Day::of::<Day__DD__>(),",
+];
+
+/// Generates the skeleton of a new day under `root` and registers it with the runner.
+/// Nothing is written if any of the files already exists.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, AocError> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::Invalid(format!("Day must be between 1 and 25, got {}", day)));
+    }
+
+    let files: Vec<(PathBuf, String)> = TEMPLATES.iter()
+        .map(|(path, template)| (root.join(fill(path, day)), fill(template, day)))
+        .collect();
+    if let Some((existing, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(AocError::Invalid(format!("{} already exists", existing.display())));
+    }
+    let lib_path = root.join("src/lib.rs");
+    let lib = read(&lib_path)?;
+    let lib = register(&lib, day)?;
+
+    for (path, content) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| io_error(dir, source))?;
+        }
+        fs::write(path, content).map_err(|source| io_error(path, source))?;
+    }
+    fs::write(&lib_path, lib).map_err(|source| io_error(&lib_path, source))?;
+
+    let mut written: Vec<PathBuf> = files.into_iter().map(|(path, _)| path).collect();
+    written.push(lib_path);
+    Ok(written)
+}
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("__DD__", &format!("{:02}", day))
+        .replace("__D__", &day.to_string())
+}
+
+/// Adds the day to module declarations, re-exports and the list of days in lib.rs.
+fn register(lib: &str, day: u8) -> Result<String, AocError> {
+    let mut lines: Vec<String> = lib.lines().map(|l| l.to_owned()).collect();
+    for registration in REGISTRATIONS {
+        let line = fill(registration, day);
+        if lines.contains(&line) {
+            return Err(AocError::Invalid(format!("Day {} is already registered in lib.rs", day)));
+        }
+        let (prefix, _) = registration.split_once("__DD__").unwrap_or((registration, ""));
+        let (_, suffix) = registration.rsplit_once("__DD__").unwrap_or(("", registration));
+        let last = lines.iter()
+            .rposition(|l| l.starts_with(prefix) && l.ends_with(suffix))
+            .ok_or_else(|| AocError::Invalid(format!("Can't find where to add '{}' in lib.rs", line.trim())))?;
+        lines.insert(last + 1, line);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|source| io_error(path, source))
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> AocError {
+    AocError::Io { path: path.display().to_string(), source }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::register;
+
+    const LIB: &str = r#"pub use day01::Day01;
+pub use day02::Day02;
+
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod parse;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<Day01>(),
+    Day::of::<Day02>(),
+];
+"#;
+
+    #[test]
+    fn test_register() {
+        let expected = r#"pub use day01::Day01;
+pub use day02::Day02;
+pub use day07::Day07;
+
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day07;
+pub mod parse;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<Day01>(),
+    Day::of::<Day02>(),
+    Day::of::<Day07>(),
+];
+"#;
+        assert_eq!(register(LIB, 7).unwrap(), expected);
+    }
+
+    #[test]
+    fn already_registered() {
+        assert!(register(LIB, 2).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::{AocError, Day, Part};
+use crate::input::{crate_path, input_path, read_input};
+
+/// Recorded answer for a single part of a day.
+#[derive(Debug, Eq, PartialEq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub input: Option<String>,
+}
+
+impl KnownAnswer {
+    /// Explicit input is relative to the crate root, by default it's the day's input of the default profile.
+    pub fn input_path(&self) -> PathBuf {
+        match &self.input {
+            Some(input) => crate_path(input),
+            None => input_path(self.day, None),
+        }
+    }
+}
+
+impl FromStr for KnownAnswer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        match fields[..] {
+            [day, part, answer] | [day, part, answer, _] => Ok(KnownAnswer {
+                day: day.parse().map_err(|_| format!("Invalid day: {}", day))?,
+                part: part.parse()?,
+                answer: answer.to_owned(),
+                input: fields.get(3).map(|input| input.to_string()),
+            }),
+            _ => Err(format!("Expected '<day> <part> <answer> [input]', got: {}", s)),
+        }
+    }
+}
+
+/// Parses the answers file, skipping blank lines and `#` comments.
+pub fn parse_answers(s: &str) -> Result<Vec<KnownAnswer>, AocError> {
+    s.lines()
+        .enumerate()
+        .map(|(idx, line)| (idx, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| line.parse()
+            .map_err(|message| AocError::Parse { line: idx + 1, column: 1, message }))
+        .collect()
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Mismatch(String),
+    Failed(String),
+}
+
+pub struct Check {
+    pub expected: KnownAnswer,
+    pub outcome: Outcome,
+}
+
+/// Runs the solution of every known answer and compares the results.
+pub fn verify(answers: Vec<KnownAnswer>) -> Vec<Check> {
+    answers.into_iter()
+        .map(|expected| {
+            let outcome = check(&expected);
+            Check { expected, outcome }
+        })
+        .collect()
+}
+
+pub fn verify_file(path: &Path) -> Result<Vec<Check>, AocError> {
+    let content = read_input(path)?;
+    Ok(verify(parse_answers(&content)?))
+}
+
+fn check(expected: &KnownAnswer) -> Outcome {
+    let day = match Day::find(expected.day) {
+        Some(day) => day,
+        None => return Outcome::Failed(format!("Day {} is not solved yet", expected.day)),
+    };
+    let input = match read_input(&expected.input_path()) {
+        Ok(input) => input,
+        Err(err) => return Outcome::Failed(err.to_string()),
+    };
+    compare(day, &input, expected.part, &expected.answer)
+}
+
+/// Solves the part and compares its answer with the expected one.
+pub fn compare(day: &Day, input: &str, part: Part, expected: &str) -> Outcome {
+    match day.solve(input, &[part]) {
+        Ok(answers) if answers[0] == expected => Outcome::Correct,
+        Ok(mut answers) => Outcome::Mismatch(answers.remove(0)),
+        Err(err) => Outcome::Failed(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Part;
+    use crate::verify::{KnownAnswer, parse_answers};
+
+    #[test]
+    fn test_parse_answers() {
+        let input = r#"
+# comment
+01 1 1446
+5 2 22083 inputs/other.txt
+"#;
+        let expected = vec![
+            KnownAnswer { day: 1, part: Part::One, answer: "1446".to_owned(), input: None },
+            KnownAnswer { day: 5, part: Part::Two, answer: "22083".to_owned(), input: Some("inputs/other.txt".to_owned()) },
+        ];
+        assert_eq!(parse_answers(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_invalid_answer_line() {
+        let result = parse_answers("01 3 1446");
+        assert_eq!(result.unwrap_err().to_string(), "Parse error at line 1, column 1: Invalid part: 3");
+    }
+}
@@ -0,0 +1,15 @@
+use std::process::ExitCode;
+
+use aoc2021::{AocError, Day__DD__, exit_code, get_input, Solution};
+
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+fn run() -> Result<(), AocError> {
+    let input = get_input(__D__)?;
+    let parsed = Day__DD__::parse(&input)?;
+    println!("Part 1: {}", Day__DD__::part1(&parsed)?);
+    println!("Part 2: {}", Day__DD__::part2(&parsed)?);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::{AocError, Solution};
+use crate::day__DD__::model::Entry;
+
+pub mod model;
+pub mod parser;
+pub mod part1;
+pub mod part2;
+
+pub struct Day__DD__;
+
+impl Solution for Day__DD__ {
+    const DAY: u8 = __D__;
+    type Input = Vec<Entry>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(entries: &Self::Input) -> Result<Self::Answer1, AocError> {
+        part1(entries)
+    }
+
+    fn part2(entries: &Self::Input) -> Result<Self::Answer2, AocError> {
+        part2(entries)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>, AocError> {
+    parser::parse(input)
+}
+
+pub fn part1(entries: &[Entry]) -> Result<u32, AocError> {
+    part1::solve(entries)
+}
+
+pub fn part2(entries: &[Entry]) -> Result<u32, AocError> {
+    part2::solve(entries)
+}
@@ -0,0 +1,4 @@
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Entry {
+    pub value: u32,
+}
@@ -0,0 +1,26 @@
+use nom::character::complete::u32 as p_u32;
+use nom::combinator::map;
+use nom::error::context;
+
+use crate::AocError;
+use crate::day__DD__::model::Entry;
+use crate::parse::{lines, parse_all, ParseResult};
+
+pub fn entry(s: &str) -> ParseResult<'_, Entry> {
+    map(context("number", p_u32), |value| Entry { value })(s)
+}
+
+pub fn parse(s: &str) -> Result<Vec<Entry>, AocError> {
+    parse_all(s, lines(entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day__DD__::model::Entry;
+    use crate::day__DD__::parser::entry;
+
+    #[test]
+    fn test_entry() {
+        assert_eq!(entry("42"), Ok(("", Entry { value: 42 })));
+    }
+}
@@ -0,0 +1,24 @@
+use crate::AocError;
+use crate::day__DD__::model::Entry;
+
+pub fn solve(_entries: &[Entry]) -> Result<u32, AocError> {
+    Err(AocError::NoSolution("Part 2 is not solved yet".to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AocError;
+    use crate::day__DD__::parser::parse;
+    use crate::day__DD__::part2::solve;
+
+    const SAMPLE_INPUT: &str = r#"
+"#;
+
+    #[test]
+    #[ignore = "sample input and answer are not filled in yet"]
+    fn solve_sample() -> Result<(), AocError> {
+        let entries = parse(SAMPLE_INPUT)?;
+        assert_eq!(solve(&entries)?, 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use aoc2021::differential::check;
+use aoc2021::{reference, DAYS};
+
+#[test]
+fn solutions_agree_with_references() {
+    for day in DAYS.iter().filter(|day| reference::for_day(day.day).is_some()) {
+        if let Some(disagreement) = check(day, 0..30, 60).unwrap() {
+            panic!("{}", disagreement);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use aoc2021::generate::{generate, Options, supports};
+use aoc2021::{Day, DAYS, Part};
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for seed in 0..5 {
+        for day in DAYS.iter().filter(|day| supports(day.day)) {
+            let options = Options { seed, size: Some(200), width: None };
+            let input = generate(day.day, &options).unwrap();
+            if let Err(err) = day.solve(&input, &Part::BOTH) {
+                panic!("Day {} failed on input generated from seed {}: {}", day.day, seed, err);
+            }
+        }
+    }
+}
+
+#[test]
+fn wide_binaries() {
+    let day = Day::find(3).unwrap();
+    for width in [20, 40] {
+        let options = Options { seed: 1, size: Some(100), width: Some(width) };
+        let input = generate(3, &options).unwrap();
+        assert!(input.lines().all(|l| l.len() == width));
+        if let Err(err) = day.solve(&input, &Part::BOTH) {
+            panic!("Day 3 failed on {} bit binaries: {}", width, err);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use aoc2021::input::crate_path;
+use aoc2021::verify::{Outcome, verify_file};
+
+#[test]
+fn known_answers_match() {
+    let checks = verify_file(&crate_path("answers.txt")).unwrap();
+    assert!(!checks.is_empty());
+    for check in checks {
+        assert_eq!(check.outcome, Outcome::Correct, "day {} part {}", check.expected.day, check.expected.part);
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use aoc2021::day02::Command;
+use aoc2021::day03::BinaryNumber;
+use aoc2021::day04::board::Board;
+use aoc2021::day05::model::{Line, Point};
+use aoc2021::generate::Rng;
+use aoc2021::parse::parse_all;
+use aoc2021::{day01, day02, day03, day04, day05, AocError};
+
+const CASES: u64 = 500;
+
+/// Checks the property on values generated from seeds `0..CASES`, reporting the seed and value that fail.
+fn for_all<T: Debug>(generate: impl Fn(&mut Rng) -> T, property: impl Fn(&T) -> Result<(), String>) {
+    for seed in 0..CASES {
+        let value = generate(&mut Rng::new(seed));
+        if let Err(err) = property(&value) {
+            panic!("Property failed for seed {} on {:?}: {}", seed, value, err);
+        }
+    }
+}
+
+fn round_trip<T, E>(value: &T, parse: impl Fn(&str) -> Result<T, E>) -> Result<(), String>
+    where T: ToString + PartialEq + Debug,
+          E: Debug
+{
+    let text = value.to_string();
+    match parse(&text) {
+        Ok(parsed) if parsed == *value => Ok(()),
+        other => Err(format!("{:?} parsed as {:?}", text, other)),
+    }
+}
+
+fn command(rng: &mut Rng) -> Command {
+    let value = match rng.below(3) {
+        0 => rng.range(0, 9),
+        1 => u32::MAX as u64,
+        _ => rng.below(u32::MAX as u64 + 1),
+    } as u32;
+    match rng.below(5) {
+        0 => Command::Forward(value),
+        1 => Command::Down(value),
+        2 => Command::Up(value),
+        3 => Command::Back(value),
+        _ => Command::Reset,
+    }
+}
+
+fn binary(rng: &mut Rng) -> BinaryNumber {
+    let width = rng.range(1, 64);
+    let bits: String = (0..width).map(|_| if rng.chance(50) { '1' } else { '0' }).collect();
+    bits.parse().unwrap()
+}
+
+fn board(rng: &mut Rng) -> Board {
+    let mut numbers: Vec<u8> = (0..=255).collect();
+    rng.shuffle(&mut numbers);
+    Board::new(numbers[..25].to_vec())
+}
+
+fn line(rng: &mut Rng) -> Line {
+    let mut coordinate = || if rng.chance(10) { u16::MAX } else { rng.below(1000) as u16 };
+    Line::new(Point::new(coordinate(), coordinate()), Point::new(coordinate(), coordinate()))
+}
+
+#[test]
+fn command_round_trip() {
+    for_all(command, |c| round_trip(c, str::parse::<Command>));
+    for_all(command, |c| round_trip(c, |s| parse_all(s, day02::script::command)));
+}
+
+#[test]
+fn binary_round_trip() {
+    for_all(binary, |b| round_trip(b, str::parse::<BinaryNumber>));
+}
+
+#[test]
+fn board_round_trip() {
+    for_all(board, |b| round_trip(b, |s| parse_all(s, day04::parser::board)));
+}
+
+#[test]
+fn line_round_trip() {
+    for_all(line, |l| round_trip(l, |s| parse_all(s, day05::parser::line)));
+}
+
+/// Valid input of a random day with a few random edits: deleted, duplicated or replaced characters.
+fn mutated_input(rng: &mut Rng) -> (u8, String) {
+    let day = rng.range(1, 5) as u8;
+    let valid = match day {
+        1 => (0..5).map(|_| format!("{}\n", rng.below(10_000))).collect(),
+        2 => (0..5).map(|_| format!("{}\n", command(rng))).collect(),
+        3 => (0..5).map(|_| format!("{}\n", binary(rng))).collect(),
+        4 => format!("1,2,3\n\n{}\n{}", board(rng), board(rng)),
+        _ => (0..5).map(|_| format!("{}\n", line(rng))).collect(),
+    };
+    let mut chars: Vec<char> = valid.chars().collect();
+    const NOISE: &[char] = &['x', '-', ',', ' ', '\n', '9', '>', 'é', '\t'];
+    for _ in 0..rng.range(1, 3) {
+        let idx = rng.below(chars.len() as u64) as usize;
+        match rng.below(3) {
+            0 => {
+                chars.remove(idx);
+            }
+            1 => chars.insert(idx, chars[idx]),
+            _ => chars[idx] = NOISE[rng.below(NOISE.len() as u64) as usize],
+        }
+    }
+    (day, chars.into_iter().collect())
+}
+
+fn parse_day(day: u8, input: &str) -> Result<(), AocError> {
+    match day {
+        1 => day01::parse(input).map(|_| ()),
+        2 => day02::parse(input).map(|_| ()),
+        3 => day03::parse(input).map(|_| ()),
+        4 => day04::parser::parse(input).map(|_| ()),
+        _ => day05::parser::parse(input).map(|_| ()),
+    }
+}
+
+#[test]
+fn bad_input_never_panics() {
+    for_all(mutated_input, |(day, input)| {
+        match catch_unwind(AssertUnwindSafe(|| parse_day(*day, input))) {
+            Ok(Ok(())) | Ok(Err(AocError::Parse { .. })) | Ok(Err(AocError::Invalid(_))) => Ok(()),
+            Ok(Err(other)) => Err(format!("unexpected error {}", other)),
+            Err(_) => Err("parser panicked".to_owned()),
+        }
+    });
+}
+
+#[test]
+fn bad_values_are_errors() {
+    for bad in ["forward", "forward -1", "forward 4294967296", "sideways 3", "down 1 2", ""] {
+        assert!(bad.parse::<Command>().is_err(), "{:?}", bad);
+    }
+    for bad in ["", "012", "1".repeat(65).as_str(), "10 1"] {
+        assert!(bad.parse::<BinaryNumber>().is_err(), "{:?}", bad);
+    }
+    for bad in ["1 2 3", &"256 ".repeat(25), &"1 ".repeat(24)] {
+        assert!(parse_all(bad, day04::parser::board).is_err(), "{:?}", bad);
+    }
+    for bad in ["1,2 -> 3", "1,2 => 3,4", "65536,0 -> 0,0", "-1,0 -> 0,0"] {
+        assert!(parse_all(bad, day05::parser::line).is_err(), "{:?}", bad);
+    }
+}
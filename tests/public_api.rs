@@ -0,0 +1,35 @@
+use aoc2021::day02::{Command, run_aim_commands, Submarine};
+use aoc2021::day03::BinaryNumber;
+use aoc2021::day04::board::Board;
+use aoc2021::day05::model::{LineType, Point};
+use aoc2021::day05::{parse, part1, part2};
+
+#[test]
+fn day02_submarine() {
+    let commands = vec![Command::Down(5), Command::Forward(8), Command::Up(3)];
+    assert_eq!(run_aim_commands(&commands).unwrap(), Submarine::new(40, 8, 2));
+}
+
+#[test]
+fn day03_binary_number() {
+    let binary: BinaryNumber = "10110".parse().unwrap();
+    assert_eq!(binary.decimal(), 22);
+}
+
+#[test]
+fn day04_board() {
+    let mut board = Board::new((0..25).collect());
+    for i in 0..5 {
+        board.select_number(i);
+    }
+    assert!(board.bingo());
+}
+
+#[test]
+fn day05_lines() {
+    let lines = parse("0,0 -> 2,2\n0,2 -> 2,0\n1,0 -> 1,2").unwrap();
+    assert_eq!(lines[0].line_type(), LineType::Diagonal);
+    assert_eq!(lines[2].line_points(), vec![Point::new(1, 0), Point::new(1, 1), Point::new(1, 2)]);
+    assert_eq!(part1(&lines), 0);
+    assert_eq!(part2(&lines), 1);
+}
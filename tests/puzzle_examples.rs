@@ -0,0 +1,14 @@
+use aoc2021::DAYS;
+use aoc2021::puzzle::{check_examples, puzzle_path};
+use aoc2021::verify::Outcome;
+
+#[test]
+fn puzzle_examples_match() {
+    for day in DAYS.iter().filter(|day| puzzle_path(day.day).exists()) {
+        let checks = check_examples(day).unwrap();
+        assert!(!checks.is_empty(), "day {} has no examples", day.day);
+        for (example, outcome) in checks {
+            assert_eq!(outcome, Outcome::Correct, "day {} part {}", day.day, example.part);
+        }
+    }
+}